@@ -1,20 +1,31 @@
-use std::env;
-use std::path::PathBuf;
-
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // 生成的 protobuf 代码已提交在 src/proto 下，只有启用 regenerate-protos 时才重新生成到 OUT_DIR，
+    // 需要先 git submodule update --init 并安装 protoc；更新 src/proto 使用 scripts/regenerate-protos.sh
+    #[cfg(feature = "regenerate-protos")]
+    regenerate_protos()?;
+    Ok(())
+}
+
+#[cfg(feature = "regenerate-protos")]
+fn regenerate_protos() -> Result<(), Box<dyn std::error::Error>> {
+    use std::path::PathBuf;
+
+    let protos = [
+        "proto/jito-protos/block_engine.proto",
+        "proto/jito-protos/bundle.proto",
+        "proto/jito-protos/packet.proto",
+        "proto/jito-protos/shared.proto",
+        "proto/jito-protos/searcher.proto",
+    ];
+    for proto in &protos {
+        println!("cargo:rerun-if-changed={}", proto);
+    }
+
+    // 构建脚本不修改源码目录
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
     tonic_build::configure()
-        .build_server(false)
+        .build_server(true)
         .out_dir(out_dir)
-        .compile(
-            &[
-                "proto/jito-protos/block_engine.proto",
-                "proto/jito-protos/bundle.proto",
-                "proto/jito-protos/packet.proto",
-                "proto/jito-protos/shared.proto",
-                "proto/jito-protos/searcher.proto",
-            ],
-            &["proto/jito-protos"],
-        )?;
+        .compile(&protos, &["proto/jito-protos"])?;
     Ok(())
-} 
\ No newline at end of file
+}
@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow};
-use jito_sdk_rust::JitoJsonRpcSDK;
+use jito_sdk_rust::{Encoding, JitoJsonRpcSDK};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     pubkey::Pubkey,
@@ -12,7 +12,6 @@ use std::str::FromStr;
 use std::fs::File;
 use std::io::BufReader;
 use serde_json::json;
-use bs58;
 use tokio::time::{sleep, Duration};
 
 #[derive(Debug)]
@@ -44,10 +43,8 @@ async fn main() -> Result<()> {
     let sender = load_keypair("/path/to/wallet.json" )?;
     println!("Sender pubkey: {}", sender.pubkey());
 
-    // Set up receiver and Jito tip account
+    // Set up receiver
     let receiver = Pubkey::from_str("YOUR_RECIEVER_PUBKEY")?;
-    let random_tip_account = jito_sdk.get_random_tip_account().await?;
-    let jito_tip_account = Pubkey::from_str(&random_tip_account)?;
 
     // Define amounts to send (in lamports)
     let main_transfer_amount = 1_000; // 0.000001 SOL
@@ -59,11 +56,7 @@ async fn main() -> Result<()> {
         &receiver,
         main_transfer_amount,
     );
-    let jito_tip_ix = system_instruction::transfer(
-        &sender.pubkey(),
-        &jito_tip_account,
-        jito_tip_amount,
-    );
+    let jito_tip_ix = jito_sdk.tip_instruction(&sender.pubkey(), jito_tip_amount).await?;
 
     // Create memo instruction
     let memo_program_id = Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")?;
@@ -84,10 +77,10 @@ async fn main() -> Result<()> {
     transaction.sign(&[&sender], recent_blockhash);
 
     // Serialize the transaction
-    let serialized_tx = bs58::encode(bincode::serialize(&transaction)?).into_string();
+    let serialized_tx = Encoding::Base64.encode_transaction(&transaction)?;
     
-    // Prepare bundle for submission (array of transactions)
-    let bundle = json!([serialized_tx]);
+    // Prepare bundle for submission: [[transactions...], options]
+    let bundle = json!([[serialized_tx], { "encoding": "base64" }]);
 
     // UUID for the bundle
     let uuid = None;
@@ -114,7 +107,7 @@ async fn main() -> Result<()> {
          if let Some(result) = status_response.get("result") {
              if let Some(value) = result.get("value") {
                  if let Some(statuses) = value.as_array() {
-                     if let Some(bundle_status) = statuses.get(0) {
+                     if let Some(bundle_status) = statuses.first() {
                          if let Some(status) = bundle_status.get("status") {
                              match status.as_str() {
                                  Some("Landed") => {
@@ -200,7 +193,7 @@ fn get_bundle_status(status_response: &serde_json::Value) -> Result<BundleStatus
         .get("result")
         .and_then(|result| result.get("value"))
         .and_then(|value| value.as_array())
-        .and_then(|statuses| statuses.get(0))
+        .and_then(|statuses| statuses.first())
         .ok_or_else(|| anyhow!("Failed to parse bundle status"))
         .map(|bundle_status| BundleStatus {
             confirmation_status: bundle_status.get("confirmation_status").and_then(|s| s.as_str()).map(String::from),
@@ -9,7 +9,6 @@ use solana_sdk::{
     transaction::Transaction,
     compute_budget::ComputeBudgetInstruction,
 };
-use base64::{Engine as _, engine::general_purpose};
 use std::str::FromStr;
 use std::fs::File;
 use std::io::BufReader;
@@ -38,10 +37,8 @@ async fn main() -> Result<()> {
     
     println!("Sender pubkey: {}", sender.pubkey());
 
-    // Set up receiver and Jito tip account
+    // Set up receiver
     let receiver = Pubkey::from_str("YOUR_RECIEVER_KEY")?;
-    let random_tip_account = jito_sdk.get_random_tip_account().await?;
-    let jito_tip_account = Pubkey::from_str(&random_tip_account)?;
 
     // Define amounts to send (in lamports)
     let main_transfer_amount = 1_000; // 0.000001 SOL
@@ -58,11 +55,7 @@ async fn main() -> Result<()> {
         &receiver,
         main_transfer_amount,
     );
-    let jito_tip_ix = system_instruction::transfer(
-        &sender.pubkey(),
-        &jito_tip_account,
-        jito_tip_amount,
-    );
+    let jito_tip_ix = jito_sdk.tip_instruction(&sender.pubkey(), jito_tip_amount).await?;
 
     // Create transaction with all instructions
     let mut transaction = Transaction::new_with_payer(
@@ -77,7 +70,7 @@ async fn main() -> Result<()> {
     transaction.sign(&[&sender], recent_blockhash);
 
     // Serialize the full transaction
-    let serialized_tx = general_purpose::STANDARD.encode(bincode::serialize(&transaction)?);
+    let serialized_tx = jito_sdk.encoding().encode_transaction(&transaction)?;
 
     // Send transaction using Jito SDK
     println!("Sending transaction...");
@@ -93,12 +86,12 @@ async fn main() -> Result<()> {
     println!("Transaction sent with signature: {}", signature);
 
     // Confirm transaction
-    let confirmation = solana_rpc.confirm_transaction_with_spinner(
+    solana_rpc.confirm_transaction_with_spinner(
         &signature.parse()?,
         &solana_rpc.get_latest_blockhash()?,
         CommitmentConfig::confirmed(),
     )?;
-    println!("Transaction confirmed: {:?}", confirmation);
+    println!("Transaction confirmed");
 
     println!("View transaction on Solscan: https://solscan.io/tx/{}", signature);
 
@@ -1,4 +1,4 @@
-use jito_sdk_rust::{GrpcClient, JitoJsonRpcSDK, proto::searcher::GetTipAccountsRequest};
+use jito_sdk_rust::GrpcClient;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -8,7 +8,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 pub async fn get_tip_accounts() -> Result<(), Box<dyn std::error::Error>> {
     // 连接到 Jito 的服务端点
-    let mut client = GrpcClient::connect("https://mainnet.block-engine.jito.wtf").await?;
+    let client = GrpcClient::connect("https://mainnet.block-engine.jito.wtf").await?;
     
     // 获取小费账户
     match client.get_tip_accounts().await {
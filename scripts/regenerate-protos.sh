@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+# 从 proto/jito-protos 子模块重新生成 src/proto 下提交的代码，需要安装 protoc。
+# build.rs 只把代码生成到 OUT_DIR，这里把生成结果复制回源码目录
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+if [ ! -f proto/jito-protos/searcher.proto ]; then
+    git submodule update --init proto/jito-protos
+fi
+
+out_dir=$(cargo build --features regenerate-protos --message-format=json \
+    | grep -o '"out_dir":"[^"]*jito-sdk-rust-[^"]*"' \
+    | tail -n 1 \
+    | cut -d'"' -f4)
+if [ -z "$out_dir" ]; then
+    echo "could not find the build script output directory" >&2
+    exit 1
+fi
+
+for package in block_engine bundle packet searcher shared; do
+    cp "$out_dir/$package.rs" "src/proto/$package.rs"
+done
+echo "regenerated src/proto from $out_dir"
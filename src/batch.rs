@@ -0,0 +1,263 @@
+use crate::{metrics, next_request_id, JitoJsonRpcSDK};
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::time::Instant;
+
+// 批量请求中某个调用的句柄，用于从 BatchResults 取出对应的响应
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchCall(usize);
+
+// 一次发往 /bundles 的 JSON-RPC 2.0 批量请求，服务端不支持批量时逐个发送
+pub struct JsonRpcBatch<'a> {
+    sdk: &'a JitoJsonRpcSDK,
+    calls: Vec<(String, Value)>, // 方法名和参数
+}
+
+impl<'a> JsonRpcBatch<'a> {
+    pub fn new(sdk: &'a JitoJsonRpcSDK) -> Self {
+        Self {
+            sdk,
+            calls: Vec::new(),
+        }
+    }
+
+    // 添加任意方法调用
+    pub fn call(&mut self, method: &str, params: Value) -> BatchCall {
+        self.calls.push((method.to_string(), params));
+        BatchCall(self.calls.len() - 1)
+    }
+
+    pub fn get_tip_accounts(&mut self) -> BatchCall {
+        self.call("getTipAccounts", json!([]))
+    }
+
+    pub fn get_bundle_statuses(&mut self, bundle_uuids: Vec<String>) -> BatchCall {
+        self.call("getBundleStatuses", json!([bundle_uuids]))
+    }
+
+    pub fn get_in_flight_bundle_statuses(&mut self, bundle_uuids: Vec<String>) -> BatchCall {
+        self.call("getInflightBundleStatuses", json!([bundle_uuids]))
+    }
+
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    pub async fn send(self) -> Result<BatchResults> {
+        if self.calls.is_empty() {
+            return Ok(BatchResults {
+                responses: Vec::new(),
+                batched: false,
+            });
+        }
+
+        let ids = self
+            .calls
+            .iter()
+            .map(|_| next_request_id())
+            .collect::<Vec<_>>();
+        let data = self
+            .calls
+            .iter()
+            .zip(&ids)
+            .map(|((method, params), id)| {
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "method": method,
+                    "params": params,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        match self.send_batch(&Value::Array(data)).await? {
+            Some(Value::Array(responses)) => Ok(BatchResults {
+                responses: demultiplex(&ids, responses),
+                batched: true,
+            }),
+            _ => {
+                // 服务端拒绝批量请求，退回为逐个发送
+                tracing::warn!("batch request rejected, falling back to sequential requests");
+                let endpoint = self.sdk.bundles_endpoint();
+                let mut responses = Vec::with_capacity(self.calls.len());
+                for (method, params) in self.calls {
+                    let response = self
+                        .sdk
+                        .send_request(&endpoint, &method, Some(params))
+                        .await;
+                    responses.push(Some(response));
+                }
+                Ok(BatchResults {
+                    responses,
+                    batched: false,
+                })
+            }
+        }
+    }
+
+    // 发送批量请求；返回 None 表示服务端不支持批量（非数组响应或非 2xx 状态码）
+    async fn send_batch(&self, data: &Value) -> Result<Option<Value>> {
+        let sdk = self.sdk;
+        let url = format!("{}{}", sdk.base_url, sdk.bundles_endpoint());
+        tracing::debug!(%url, calls = self.calls.len(), "sending batch request");
+
+        let started = Instant::now();
+        let response = match sdk.transport.post(&url, data).await {
+            Ok(response) => response,
+            Err(e) => {
+                sdk.metrics.record_request(
+                    "json_rpc",
+                    "batch",
+                    metrics::OUTCOME_TRANSPORT_ERROR,
+                    started.elapsed(),
+                );
+                return Err(anyhow!("Batch request error: {}", e));
+            }
+        };
+
+        let outcome = match &response.body {
+            _ if response.is_rate_limited() => metrics::OUTCOME_RATE_LIMITED,
+            Some(Value::Array(_)) if response.is_success() => metrics::OUTCOME_OK,
+            Some(_) if response.is_success() => metrics::OUTCOME_RPC_ERROR,
+            Some(_) => metrics::OUTCOME_HTTP_ERROR,
+            None => metrics::OUTCOME_DECODE_ERROR,
+        };
+        sdk.metrics
+            .record_request("json_rpc", "batch", outcome, started.elapsed());
+
+        if response.is_rate_limited() {
+            return Err(anyhow!("Batch request error: rate limited"));
+        }
+        let success = response.is_success();
+        Ok(response.body.filter(|body| success && body.is_array()))
+    }
+}
+
+// 按 id 把响应对应回调用，批量响应的顺序不一定与请求一致
+fn demultiplex(ids: &[u64], responses: Vec<Value>) -> Vec<Option<Result<Value>>> {
+    let mut results = ids.iter().map(|_| None).collect::<Vec<_>>();
+    for response in responses {
+        let id = response.get("id").and_then(Value::as_u64);
+        if let Some(index) = ids.iter().position(|sent| Some(*sent) == id) {
+            results[index] = Some(Ok(response));
+        }
+    }
+    results
+        .into_iter()
+        .zip(ids)
+        .map(|(result, id)| {
+            result.or_else(|| {
+                Some(Err(anyhow!(
+                    "Request error (id {}): missing response in batch",
+                    id
+                )))
+            })
+        })
+        .collect()
+}
+
+// getBundleStatuses 返回的单个 bundle 状态
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BundleStatus {
+    pub bundle_id: String,
+    #[serde(default)]
+    pub transactions: Vec<String>,
+    pub slot: u64,
+    pub confirmation_status: Option<String>,
+    pub err: Value, // 成功时为 {"Ok": null}
+}
+
+// getInflightBundleStatuses 返回的单个 bundle 状态，status 为 Invalid/Pending/Failed/Landed
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InflightBundleStatus {
+    pub bundle_id: String,
+    pub status: String,
+    pub landed_slot: Option<u64>,
+}
+
+// 带 context 的 JSON-RPC 结果
+#[derive(Deserialize)]
+struct WithContext<T> {
+    value: T,
+}
+
+// 批量请求的结果，每个调用对应一个完整的 JSON-RPC 响应（与单独调用时的返回值相同）
+#[derive(Debug)]
+pub struct BatchResults {
+    responses: Vec<Option<Result<Value>>>,
+    batched: bool,
+}
+
+impl BatchResults {
+    // 是否以一次批量请求完成，false 表示退回了逐个发送
+    pub fn was_batched(&self) -> bool {
+        self.batched
+    }
+
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+
+    // 取出某个调用的响应，每个调用只能取一次
+    pub fn take(&mut self, call: BatchCall) -> Result<Value> {
+        self.responses
+            .get_mut(call.0)
+            .and_then(Option::take)
+            .unwrap_or_else(|| Err(anyhow!("No response for batch call {}", call.0)))
+    }
+
+    // 取出某个调用的 result 并反序列化，JSON-RPC 错误作为 Err 返回
+    pub fn take_result<T: DeserializeOwned>(&mut self, call: BatchCall) -> Result<T> {
+        let mut response = self.take(call)?;
+        if let Some(error) = response.get("error") {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown error");
+            return Err(anyhow!(
+                "Request error (batch call {}): {}",
+                call.0,
+                message
+            ));
+        }
+        let result = response
+            .get_mut("result")
+            .map(Value::take)
+            .ok_or_else(|| anyhow!("Missing result for batch call {}", call.0))?;
+        serde_json::from_value(result)
+            .map_err(|e| anyhow!("Invalid result for batch call {}: {}", call.0, e))
+    }
+
+    pub fn take_tip_accounts(&mut self, call: BatchCall) -> Result<Vec<String>> {
+        self.take_result(call)
+    }
+
+    // 与请求的 bundle id 一一对应，尚未落地的 bundle 为 None
+    pub fn take_bundle_statuses(&mut self, call: BatchCall) -> Result<Vec<Option<BundleStatus>>> {
+        Ok(self.take_result::<WithContext<_>>(call)?.value)
+    }
+
+    pub fn take_in_flight_bundle_statuses(
+        &mut self,
+        call: BatchCall,
+    ) -> Result<Vec<InflightBundleStatus>> {
+        Ok(self.take_result::<WithContext<_>>(call)?.value)
+    }
+}
+
+impl JitoJsonRpcSDK {
+    // 创建批量请求
+    pub fn batch(&self) -> JsonRpcBatch<'_> {
+        JsonRpcBatch::new(self)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::output::OutputFormat;
+use crate::Cli;
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+pub const DEFAULT_BLOCK_ENGINE_URL: &str = "https://mainnet.block-engine.jito.wtf/api/v1";
+pub const DEFAULT_GRPC_URL: &str = "https://mainnet.block-engine.jito.wtf";
+
+// 配置文件格式 (TOML)，所有字段都是可选的
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    block_engine_url: Option<String>,
+    grpc_url: Option<String>,
+    uuid: Option<String>,
+    output: Option<OutputFormat>,
+}
+
+// 最终生效的配置，优先级：命令行参数 > 环境变量 > 配置文件 > 默认值
+#[derive(Debug)]
+pub struct Config {
+    pub block_engine_url: String,
+    pub grpc_url: String,
+    pub uuid: Option<String>,
+    pub output: OutputFormat,
+}
+
+impl Config {
+    pub fn load(cli: &Cli) -> Result<Self> {
+        let file = match &cli.config {
+            Some(path) => read_config_file(path)?,
+            None => match default_config_path() {
+                Some(path) if path.exists() => read_config_file(&path)?,
+                _ => ConfigFile::default(),
+            },
+        };
+
+        Ok(Self {
+            block_engine_url: cli
+                .block_engine_url
+                .clone()
+                .or(file.block_engine_url)
+                .unwrap_or_else(|| DEFAULT_BLOCK_ENGINE_URL.to_string()),
+            grpc_url: cli
+                .grpc_url
+                .clone()
+                .or(file.grpc_url)
+                .unwrap_or_else(|| DEFAULT_GRPC_URL.to_string()),
+            uuid: cli.uuid.clone().or(file.uuid),
+            output: cli.output.or(file.output).unwrap_or_default(),
+        })
+    }
+}
+
+// ~/.config/jito/config.toml
+fn default_config_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/jito/config.toml"))
+}
+
+fn read_config_file(path: &PathBuf) -> Result<ConfigFile> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read config file {}: {}", path.display(), e))?;
+    toml::from_str(&content)
+        .map_err(|e| anyhow!("Failed to parse config file {}: {}", path.display(), e))
+}
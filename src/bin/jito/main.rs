@@ -0,0 +1,300 @@
+mod config;
+mod output;
+mod watch;
+
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use config::Config;
+use jito_sdk_rust::{Encoding, GrpcClient, JitoJsonRpcSDK};
+use output::{cell, print_json, print_table, OutputFormat};
+use serde_json::{json, Value};
+use solana_sdk::transaction::VersionedTransaction;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(name = "jito", about = "Command-line client for the Jito Block Engine")]
+pub struct Cli {
+    /// Path to a TOML config file (default: ~/.config/jito/config.toml)
+    #[arg(long, env = "JITO_CONFIG", global = true)]
+    config: Option<PathBuf>,
+
+    /// JSON-RPC base URL, e.g. https://mainnet.block-engine.jito.wtf/api/v1
+    #[arg(long, env = "JITO_BLOCK_ENGINE_URL", global = true)]
+    block_engine_url: Option<String>,
+
+    /// gRPC URL, e.g. https://mainnet.block-engine.jito.wtf
+    #[arg(long, env = "JITO_GRPC_URL", global = true)]
+    grpc_url: Option<String>,
+
+    /// UUID / API key sent with JSON-RPC requests
+    #[arg(long, env = "JITO_UUID", global = true)]
+    uuid: Option<String>,
+
+    /// Output format
+    #[arg(long, short, value_enum, env = "JITO_OUTPUT", global = true)]
+    output: Option<OutputFormat>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List tip accounts
+    TipAccounts,
+    /// Send a bundle of 1-5 transactions (file paths or base64 strings)
+    SendBundle {
+        #[arg(required = true, num_args = 1..=5)]
+        transactions: Vec<String>,
+    },
+    /// Send a single transaction (file path or base64 string)
+    SendTx {
+        transaction: String,
+        /// Only send the transaction as a single-transaction bundle
+        #[arg(long)]
+        bundle_only: bool,
+        #[arg(long)]
+        skip_preflight: bool,
+    },
+    /// Get statuses of landed bundles
+    BundleStatus {
+        #[arg(required = true)]
+        bundle_ids: Vec<String>,
+    },
+    /// Get statuses of in-flight bundles
+    InflightStatus {
+        #[arg(required = true)]
+        bundle_ids: Vec<String>,
+    },
+    /// Show the next scheduled Jito leader (gRPC)
+    NextLeader {
+        /// Restrict to these regions
+        #[arg(long = "region")]
+        regions: Vec<String>,
+    },
+    /// List block engine regions (gRPC)
+    Regions,
+    /// Watch bundles until they finalize or fail (ids from arguments or stdin).
+    /// Exit codes: 0 finalized, 2 failed, 3 invalid (unknown bundle id), 4 timed out.
+    /// Failed status queries are logged and retried until the timeout
+    Watch {
+        /// Bundle ids; read from stdin when empty or "-"
+        bundle_ids: Vec<String>,
+        /// Poll interval in milliseconds
+        #[arg(long, default_value_t = 1000)]
+        interval_ms: u64,
+        /// Give up after this many seconds
+        #[arg(long, default_value_t = 120)]
+        timeout_secs: u64,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // 警告（例如 watch 轮询失败）输出到 stderr，stdout 只用于命令结果
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_max_level(tracing::Level::WARN)
+        .without_time()
+        .init();
+
+    let cli = Cli::parse();
+    let config = Config::load(&cli)?;
+    let sdk = JitoJsonRpcSDK::new(&config.block_engine_url, config.uuid.clone());
+
+    match &cli.command {
+        Command::TipAccounts => {
+            let response = sdk.get_tip_accounts().await?;
+            let result = rpc_result(response)?;
+            match config.output {
+                OutputFormat::Json => print_json(&result),
+                OutputFormat::Table => {
+                    let rows = as_array(&result)
+                        .iter()
+                        .map(|account| vec![cell(account)])
+                        .collect::<Vec<_>>();
+                    print_table(&["TIP ACCOUNT"], &rows);
+                }
+            }
+        }
+        Command::SendBundle { transactions } => {
+            let encoded = transactions
+                .iter()
+                .map(|tx| load_transaction(tx))
+                .collect::<Result<Vec<_>>>()?;
+            let params = json!([encoded]);
+            let response = sdk.send_bundle(Some(params), config.uuid.as_deref()).await?;
+            let result = rpc_result(response)?;
+            match config.output {
+                OutputFormat::Json => print_json(&json!({ "bundle_id": result })),
+                OutputFormat::Table => print_table(&["BUNDLE ID"], &[vec![cell(&result)]]),
+            }
+        }
+        Command::SendTx {
+            transaction,
+            bundle_only,
+            skip_preflight,
+        } => {
+            let params = json!({
+                "tx": load_transaction(transaction)?,
+                "skipPreflight": skip_preflight,
+            });
+            let response = sdk.send_txn(Some(params), *bundle_only).await?;
+            let result = rpc_result(response)?;
+            match config.output {
+                OutputFormat::Json => print_json(&json!({ "signature": result })),
+                OutputFormat::Table => print_table(&["SIGNATURE"], &[vec![cell(&result)]]),
+            }
+        }
+        Command::BundleStatus { bundle_ids } => {
+            let response = sdk.get_bundle_statuses(bundle_ids.clone()).await?;
+            let result = rpc_result(response)?;
+            match config.output {
+                OutputFormat::Json => print_json(&result),
+                OutputFormat::Table => {
+                    let rows = bundle_ids
+                        .iter()
+                        .zip(as_array(&result["value"]).iter().chain(std::iter::repeat(&Value::Null)))
+                        .map(|(id, status)| {
+                            vec![
+                                id.clone(),
+                                cell(&status["slot"]),
+                                cell(&status["confirmation_status"]),
+                                cell(&status["err"]),
+                            ]
+                        })
+                        .collect::<Vec<_>>();
+                    print_table(&["BUNDLE ID", "SLOT", "STATUS", "ERR"], &rows);
+                }
+            }
+        }
+        Command::InflightStatus { bundle_ids } => {
+            let response = sdk.get_in_flight_bundle_statuses(bundle_ids.clone()).await?;
+            let result = rpc_result(response)?;
+            match config.output {
+                OutputFormat::Json => print_json(&result),
+                OutputFormat::Table => {
+                    let rows = as_array(&result["value"])
+                        .iter()
+                        .map(|status| {
+                            vec![
+                                cell(&status["bundle_id"]),
+                                cell(&status["status"]),
+                                cell(&status["landed_slot"]),
+                            ]
+                        })
+                        .collect::<Vec<_>>();
+                    print_table(&["BUNDLE ID", "STATUS", "LANDED SLOT"], &rows);
+                }
+            }
+        }
+        Command::NextLeader { regions } => {
+            let client = GrpcClient::connect(&config.grpc_url).await?;
+            let leader = client.get_next_scheduled_leader(regions.clone()).await?;
+            let value = json!({
+                "current_slot": leader.current_slot,
+                "next_leader_slot": leader.next_leader_slot,
+                "next_leader_identity": leader.next_leader_identity,
+                "next_leader_region": leader.next_leader_region,
+            });
+            match config.output {
+                OutputFormat::Json => print_json(&value),
+                OutputFormat::Table => print_key_values(&value),
+            }
+        }
+        Command::Regions => {
+            let client = GrpcClient::connect(&config.grpc_url).await?;
+            let regions = client.get_regions().await?;
+            match config.output {
+                OutputFormat::Json => print_json(&json!({
+                    "current_region": regions.current_region,
+                    "available_regions": regions.available_regions,
+                })),
+                OutputFormat::Table => {
+                    let rows = regions
+                        .available_regions
+                        .iter()
+                        .map(|region| {
+                            let current = if *region == regions.current_region { "*" } else { "" };
+                            vec![region.clone(), current.to_string()]
+                        })
+                        .collect::<Vec<_>>();
+                    print_table(&["REGION", "CURRENT"], &rows);
+                }
+            }
+        }
+        Command::Watch {
+            bundle_ids,
+            interval_ms,
+            timeout_secs,
+        } => {
+            let bundle_ids = watch::read_bundle_ids(bundle_ids)?;
+            let code = watch::run(
+                &sdk,
+                bundle_ids,
+                Duration::from_millis(*interval_ms),
+                Duration::from_secs(*timeout_secs),
+                config.output,
+            )
+            .await?;
+            std::process::exit(code);
+        }
+    }
+
+    Ok(())
+}
+
+// 取出 JSON-RPC 响应的 result，error 转为错误
+fn rpc_result(response: Value) -> Result<Value> {
+    if let Some(error) = response.get("error") {
+        let error_msg = error
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("Unknown error");
+        return Err(anyhow!("JSON-RPC error: {}", error_msg));
+    }
+    response
+        .get("result")
+        .cloned()
+        .ok_or_else(|| anyhow!("Missing result in response"))
+}
+
+fn as_array(value: &Value) -> Vec<Value> {
+    value.as_array().cloned().unwrap_or_default()
+}
+
+fn print_key_values(value: &Value) {
+    let rows = value
+        .as_object()
+        .map(|map| {
+            map.iter()
+                .map(|(key, value)| vec![key.clone(), cell(value)])
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    print_table(&["KEY", "VALUE"], &rows);
+}
+
+// 读取交易：文件（二进制或 base64 文本）或 base64 字符串，统一返回 base64
+fn load_transaction(input: &str) -> Result<String> {
+    let bytes = if Path::new(input).is_file() {
+        let content = std::fs::read(input)
+            .map_err(|e| anyhow!("Failed to read transaction file {}: {}", input, e))?;
+        match std::str::from_utf8(&content)
+            .ok()
+            .and_then(|text| Encoding::Base64.decode(text.trim()).ok())
+        {
+            Some(decoded) => decoded,
+            None => content,
+        }
+    } else {
+        Encoding::Base64
+            .decode(input.trim())
+            .map_err(|e| anyhow!("{} is neither a file nor base64: {}", input, e))?
+    };
+
+    bincode::deserialize::<VersionedTransaction>(&bytes)
+        .map_err(|e| anyhow!("Failed to deserialize transaction {}: {}", input, e))?;
+    Ok(Encoding::Base64.encode(&bytes))
+}
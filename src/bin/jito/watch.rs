@@ -0,0 +1,118 @@
+use crate::output::OutputFormat;
+use anyhow::{anyhow, Result};
+use jito_sdk_rust::{BundleState, BundleTracker, BundleTransition, JitoJsonRpcSDK};
+use serde_json::json;
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+// 退出码，多个 bundle 时取最差的结果：failed > invalid > timeout > finalized
+pub const EXIT_FINALIZED: i32 = 0;
+pub const EXIT_FAILED: i32 = 2;
+pub const EXIT_INVALID: i32 = 3;
+pub const EXIT_TIMEOUT: i32 = 4;
+
+// 参数为空或为 "-" 时从 stdin 读取 bundle id（空白分隔）
+pub fn read_bundle_ids(args: &[String]) -> Result<Vec<String>> {
+    if !args.is_empty() && args.iter().all(|id| id != "-") {
+        return Ok(args.to_vec());
+    }
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| anyhow!("Failed to read bundle ids from stdin: {}", e))?;
+    let mut ids = args
+        .iter()
+        .filter(|id| *id != "-")
+        .cloned()
+        .collect::<Vec<_>>();
+    ids.extend(input.split_whitespace().map(String::from));
+    if ids.is_empty() {
+        return Err(anyhow!("No bundle ids to watch"));
+    }
+    Ok(ids)
+}
+
+// 轮询 bundle 状态并输出状态变化，返回退出码
+pub async fn run(
+    sdk: &JitoJsonRpcSDK,
+    bundle_ids: Vec<String>,
+    interval: Duration,
+    timeout: Duration,
+    output: OutputFormat,
+) -> Result<i32> {
+    let started = Instant::now();
+    let mut tracker = BundleTracker::new();
+    for id in &bundle_ids {
+        tracker.track(id, 0);
+    }
+
+    loop {
+        // 查询失败（网络错误、限流等）只记录警告，继续轮询直到超时
+        match tracker.poll(sdk).await {
+            Ok(transitions) => {
+                for transition in transitions {
+                    print_transition(&transition, output);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to poll bundle statuses: {}", e),
+        }
+        if is_done(&tracker) || started.elapsed() >= timeout {
+            break;
+        }
+        tokio::time::sleep(interval).await;
+    }
+
+    let states = tracker
+        .bundles()
+        .iter()
+        .map(|b| b.state)
+        .collect::<Vec<_>>();
+    let code = if states.contains(&BundleState::Failed) {
+        EXIT_FAILED
+    } else if states.contains(&BundleState::Invalid) {
+        EXIT_INVALID
+    } else if states
+        .iter()
+        .any(|s| !s.is_final() && *s != BundleState::Invalid)
+    {
+        EXIT_TIMEOUT
+    } else {
+        EXIT_FINALIZED
+    };
+    Ok(code)
+}
+
+// watch 把 Invalid（Jito 查不到该 bundle id，例如输错或已超出查询窗口）也视为结束，
+// 不必等到超时；其余 bundle 仍在变化时 Invalid 的 bundle 继续被查询
+fn is_done(tracker: &BundleTracker) -> bool {
+    tracker
+        .bundles()
+        .iter()
+        .all(|b| b.state.is_final() || b.state == BundleState::Invalid)
+}
+
+fn print_transition(transition: &BundleTransition, output: OutputFormat) {
+    match output {
+        OutputFormat::Json => println!(
+            "{}",
+            json!({
+                "bundle_id": transition.bundle_id,
+                "from": transition.from.to_string(),
+                "to": transition.to.to_string(),
+                "slot": transition.slot,
+                "latency_ms": transition.elapsed.as_millis() as u64,
+            })
+        ),
+        OutputFormat::Table => println!(
+            "{:>8}ms  {}  {} -> {}  slot={}",
+            transition.elapsed.as_millis(),
+            transition.bundle_id,
+            transition.from,
+            transition.to,
+            transition
+                .slot
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "-".to_string())
+        ),
+    }
+}
@@ -0,0 +1,183 @@
+use crate::transport::{JsonRpcTransport, ReqwestTransport};
+use crate::{check_response_id, next_request_id};
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use solana_sdk::hash::Hash;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+// 默认每 2 秒刷新一次，约 5 个 slot
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+// getLatestBlockhash 的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatestBlockhash {
+    pub blockhash: Hash,
+    pub last_valid_block_height: u64,
+    pub slot: u64,
+}
+
+#[derive(Debug, Default)]
+struct BlockhashState {
+    latest: Option<LatestBlockhash>,
+    block_height: Option<u64>,
+}
+
+// 在后台定期刷新最新 blockhash 和当前区块高度，供构建 bundle 和判断过期使用
+pub struct BlockhashManager {
+    rpc_url: String,
+    transport: Arc<dyn JsonRpcTransport>,
+    refresh_interval: Duration,
+    state: Arc<RwLock<BlockhashState>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl BlockhashManager {
+    // rpc_url 为 Solana RPC 地址，例如 https://api.mainnet-beta.solana.com
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_url: rpc_url.to_string(),
+            transport: Arc::new(ReqwestTransport::default()),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            state: Arc::new(RwLock::new(BlockhashState::default())),
+            task: None,
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn JsonRpcTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    // 先同步刷新一次，再启动后台刷新任务；后台刷新失败时保留上一次的结果
+    pub async fn start(mut self) -> Result<Self> {
+        self.refresh().await?;
+        let rpc_url = self.rpc_url.clone();
+        let transport = self.transport.clone();
+        let state = self.state.clone();
+        let interval = self.refresh_interval;
+        self.task = Some(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if let Err(e) = refresh_state(&rpc_url, transport.as_ref(), &state).await {
+                    tracing::warn!("Failed to refresh blockhash: {}", e);
+                }
+            }
+        }));
+        Ok(self)
+    }
+
+    // 立即查询一次最新 blockhash 和区块高度
+    pub async fn refresh(&self) -> Result<LatestBlockhash> {
+        refresh_state(&self.rpc_url, self.transport.as_ref(), &self.state).await
+    }
+
+    // 最近一次获取的 blockhash，尚未刷新过时为 None
+    pub fn latest(&self) -> Option<LatestBlockhash> {
+        self.state.read().unwrap().latest
+    }
+
+    // 最近一次获取的 blockhash，没有时报错
+    pub fn blockhash(&self) -> Result<LatestBlockhash> {
+        self.latest()
+            .ok_or_else(|| anyhow!("No blockhash available, call refresh or start first"))
+    }
+
+    pub fn block_height(&self) -> Option<u64> {
+        self.state.read().unwrap().block_height
+    }
+
+    // 以 last_valid_block_height 为截止的 blockhash 在当前区块高度下是否仍然有效
+    pub fn is_valid(&self, last_valid_block_height: u64) -> bool {
+        !matches!(self.block_height(), Some(height) if height > last_valid_block_height)
+    }
+}
+
+impl Drop for BlockhashManager {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+async fn refresh_state(
+    rpc_url: &str,
+    transport: &dyn JsonRpcTransport,
+    state: &RwLock<BlockhashState>,
+) -> Result<LatestBlockhash> {
+    let result = rpc_call(
+        rpc_url,
+        transport,
+        "getLatestBlockhash",
+        json!([{ "commitment": "confirmed" }]),
+    )
+    .await?;
+    let latest = LatestBlockhash {
+        blockhash: result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing blockhash in getLatestBlockhash response"))?
+            .parse()
+            .map_err(|e| anyhow!("Invalid blockhash: {}", e))?,
+        last_valid_block_height: result["value"]["lastValidBlockHeight"]
+            .as_u64()
+            .ok_or_else(|| {
+                anyhow!("Missing lastValidBlockHeight in getLatestBlockhash response")
+            })?,
+        slot: result["context"]["slot"].as_u64().unwrap_or_default(),
+    };
+    let block_height = rpc_call(
+        rpc_url,
+        transport,
+        "getBlockHeight",
+        json!([{ "commitment": "confirmed" }]),
+    )
+    .await?
+    .as_u64()
+    .ok_or_else(|| anyhow!("Invalid getBlockHeight response"))?;
+
+    let mut state = state.write().unwrap();
+    state.latest = Some(latest);
+    state.block_height = Some(block_height);
+    Ok(latest)
+}
+
+// 发送一次 Solana JSON-RPC 调用，返回 result
+pub(crate) async fn rpc_call(
+    rpc_url: &str,
+    transport: &dyn JsonRpcTransport,
+    method: &str,
+    params: Value,
+) -> Result<Value> {
+    let id = next_request_id();
+    let data = json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": method,
+        "params": params,
+    });
+    let response = transport
+        .post(rpc_url, &data)
+        .await
+        .map_err(|e| anyhow!("{} request error: {}", method, e))?;
+    let body = response.body.ok_or_else(|| {
+        anyhow!(
+            "{} failed: invalid response (HTTP {})",
+            method,
+            response.status
+        )
+    })?;
+    check_response_id(&body, id)?;
+    if let Some(error) = body.get("error") {
+        return Err(anyhow!("{} failed: {}", method, error));
+    }
+    body.get("result")
+        .cloned()
+        .ok_or_else(|| anyhow!("Missing result in {} response", method))
+}
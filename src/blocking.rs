@@ -0,0 +1,153 @@
+// 同步版本的客户端，内部持有一个单线程 tokio runtime，用法类似 reqwest::blocking。
+// 不能在异步 runtime 内调用，否则 block_on 会 panic。
+use crate::Encoding;
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+#[cfg(feature = "solana")]
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, transaction::VersionedTransaction};
+use std::future::Future;
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "grpc")]
+use crate::proto::searcher::{
+    GetRegionsResponse, GetTipAccountsResponse, NextScheduledLeaderResponse,
+};
+
+fn new_runtime() -> Result<Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| anyhow!("Failed to build runtime: {}", e))
+}
+
+// 同步 JSON-RPC SDK，方法与 crate::JitoJsonRpcSDK 一一对应
+pub struct JitoJsonRpcSDK {
+    inner: crate::JitoJsonRpcSDK,
+    runtime: Runtime,
+}
+
+impl JitoJsonRpcSDK {
+    pub fn new(base_url: &str, uuid: Option<String>) -> Result<Self> {
+        Ok(Self {
+            inner: crate::JitoJsonRpcSDK::new(base_url, uuid),
+            runtime: new_runtime()?,
+        })
+    }
+
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.inner = self.inner.with_encoding(encoding);
+        self
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.inner.encoding()
+    }
+
+    #[cfg(feature = "grpc")]
+    pub fn with_grpc_url(mut self, grpc_url: &str) -> Self {
+        self.inner = self.inner.with_grpc_url(grpc_url);
+        self
+    }
+
+    // 异步 SDK 的引用，可与 block_on 配合调用未封装的方法
+    pub fn inner(&self) -> &crate::JitoJsonRpcSDK {
+        &self.inner
+    }
+
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    pub fn get_random_tip_account(&self) -> Result<String> {
+        self.block_on(self.inner.get_random_tip_account())
+    }
+
+    #[cfg(feature = "solana")]
+    pub fn random_tip_pubkey(&self) -> Result<Pubkey> {
+        self.block_on(self.inner.random_tip_pubkey())
+    }
+
+    #[cfg(feature = "solana")]
+    pub fn tip_instruction(&self, payer: &Pubkey, lamports: u64) -> Result<Instruction> {
+        self.block_on(self.inner.tip_instruction(payer, lamports))
+    }
+
+    pub fn get_bundle_statuses(&self, bundle_uuids: Vec<String>) -> Result<Value> {
+        self.block_on(self.inner.get_bundle_statuses(bundle_uuids))
+    }
+
+    pub fn get_in_flight_bundle_statuses(&self, bundle_uuids: Vec<String>) -> Result<Value> {
+        self.block_on(self.inner.get_in_flight_bundle_statuses(bundle_uuids))
+    }
+
+    pub fn get_tip_accounts(&self) -> Result<Value> {
+        self.block_on(self.inner.get_tip_accounts())
+    }
+
+    #[cfg(feature = "grpc")]
+    pub fn get_tip_accounts_grpc(&self) -> Result<Value> {
+        self.block_on(self.inner.get_tip_accounts_grpc())
+    }
+
+    #[cfg(feature = "solana")]
+    pub fn send_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+        bundle_only: bool,
+    ) -> Result<Value> {
+        self.block_on(self.inner.send_transaction(transaction, bundle_only))
+    }
+
+    pub fn send_txn(&self, params: Option<Value>, bundle_only: bool) -> Result<Value> {
+        self.block_on(self.inner.send_txn(params, bundle_only))
+    }
+
+    pub fn send_bundle(&self, params: Option<Value>, uuid: Option<&str>) -> Result<Value> {
+        self.block_on(self.inner.send_bundle(params, uuid))
+    }
+
+    #[cfg(feature = "solana")]
+    pub fn send_bundle_transactions(
+        &self,
+        transactions: &[VersionedTransaction],
+        uuid: Option<&str>,
+    ) -> Result<Value> {
+        self.block_on(self.inner.send_bundle_transactions(transactions, uuid))
+    }
+}
+
+// 同步 gRPC 客户端，连接建立在内部 runtime 上
+#[cfg(feature = "grpc")]
+pub struct GrpcClient {
+    inner: crate::GrpcClient,
+    runtime: Runtime,
+}
+
+#[cfg(feature = "grpc")]
+impl GrpcClient {
+    pub fn connect(addr: &str) -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(crate::GrpcClient::connect(addr))?;
+        Ok(Self { inner, runtime })
+    }
+
+    pub fn get_tip_accounts(&self) -> Result<GetTipAccountsResponse> {
+        self.runtime.block_on(self.inner.get_tip_accounts())
+    }
+
+    pub fn get_next_scheduled_leader(
+        &self,
+        regions: Vec<String>,
+    ) -> Result<NextScheduledLeaderResponse> {
+        self.runtime.block_on(self.inner.get_next_scheduled_leader(regions))
+    }
+
+    pub fn get_regions(&self) -> Result<GetRegionsResponse> {
+        self.runtime.block_on(self.inner.get_regions())
+    }
+
+    #[cfg(feature = "solana")]
+    pub fn send_bundle(&self, transactions: Vec<VersionedTransaction>) -> Result<String> {
+        self.runtime.block_on(self.inner.send_bundle(transactions))
+    }
+}
@@ -0,0 +1,148 @@
+use crate::blockhash::LatestBlockhash;
+use crate::nonce::{nonced_v0_transaction, uses_durable_nonce, NonceInfo};
+use crate::signer::{sign_bundle, BundleSigner};
+use crate::tip::{append_tip_to_v0_transaction, tip_instruction_to};
+use crate::tip_strategy::{TipContext, TipStrategy};
+use crate::versioned::{check_transaction_size, unsigned_v0_transaction};
+use anyhow::{anyhow, Result};
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount, hash::Hash, instruction::Instruction,
+    pubkey::Pubkey, signature::Signature, transaction::VersionedTransaction,
+};
+
+// Jito 单个 bundle 最多包含的交易数
+pub const MAX_BUNDLE_TRANSACTIONS: usize = 5;
+
+// Bundle 构建器，按 tip 策略把 tip 指令追加到最后一笔交易
+pub struct BundleBuilder {
+    transactions: Vec<VersionedTransaction>, // 未签名的交易
+    tip_payer: Pubkey,                       // 支付 tip 的账户
+    tip_account: Pubkey,                     // Jito tip account
+    tip_strategy: Option<Box<dyn TipStrategy>>,
+    // 构建时替换所有交易的 recent blockhash
+    blockhash: Option<LatestBlockhash>,
+    signer: Option<Box<dyn BundleSigner>>,
+    // 追加 tip 时用于识别查找表已加载的账户
+    lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl BundleBuilder {
+    pub fn new(tip_payer: Pubkey, tip_account: Pubkey) -> Self {
+        Self {
+            transactions: Vec::new(),
+            tip_payer,
+            tip_account,
+            tip_strategy: None,
+            blockhash: None,
+            signer: None,
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    pub fn add_transaction(mut self, tx: impl Into<VersionedTransaction>) -> Self {
+        self.transactions.push(tx.into());
+        self
+    }
+
+    // 用指令和地址查找表编译一笔未签名的 v0 交易加入 bundle
+    pub fn add_v0_instructions(
+        self,
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        lookup_tables: &[AddressLookupTableAccount],
+        recent_blockhash: Hash,
+    ) -> Result<Self> {
+        let tx = unsigned_v0_transaction(payer, instructions, lookup_tables, recent_blockhash)?;
+        Ok(self.lookup_tables(lookup_tables).add_transaction(tx))
+    }
+
+    // 用 durable nonce 编译一笔未签名的 v0 交易加入 bundle，重新发送时无需因 blockhash 过期而重新签名
+    pub fn add_nonced_instructions(
+        self,
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        lookup_tables: &[AddressLookupTableAccount],
+        nonce: &NonceInfo,
+    ) -> Result<Self> {
+        let tx = nonced_v0_transaction(payer, nonce, instructions, lookup_tables)?;
+        Ok(self.lookup_tables(lookup_tables).add_transaction(tx))
+    }
+
+    // 通过 add_transaction 加入的 v0 交易使用地址查找表时，需要提供查找表才能追加 tip
+    pub fn lookup_tables(mut self, lookup_tables: &[AddressLookupTableAccount]) -> Self {
+        self.lookup_tables.extend_from_slice(lookup_tables);
+        self
+    }
+
+    pub fn tip_strategy(mut self, strategy: impl TipStrategy + 'static) -> Self {
+        self.tip_strategy = Some(Box::new(strategy));
+        self
+    }
+
+    // 使用 BlockhashManager 提供的 blockhash，build 时写入所有未使用 durable nonce 的交易
+    pub fn blockhash(mut self, blockhash: LatestBlockhash) -> Self {
+        self.blockhash = Some(blockhash);
+        self
+    }
+
+    // build_signed 使用的签名者，多个签名者可用 Vec<Box<dyn BundleSigner>> 组合
+    pub fn signer(mut self, signer: impl BundleSigner + 'static) -> Self {
+        self.signer = Some(Box::new(signer));
+        self
+    }
+
+    // bundle 过期的区块高度，可传给 BundleTracker::track_until
+    pub fn last_valid_block_height(&self) -> Option<u64> {
+        self.blockhash.map(|b| b.last_valid_block_height)
+    }
+
+    // 计算本次的 tip 金额，未设置策略时为 0
+    pub fn tip_lamports(&self, ctx: &TipContext) -> Result<u64> {
+        match &self.tip_strategy {
+            Some(strategy) => strategy.tip_lamports(ctx),
+            None => Ok(0),
+        }
+    }
+
+    // 生成追加了 tip 的交易列表，签名已被重置，需要调用方签名
+    pub fn build(&self, ctx: &TipContext) -> Result<Vec<VersionedTransaction>> {
+        if self.transactions.is_empty() {
+            return Err(anyhow!("Bundle must contain at least one transaction"));
+        }
+        if self.transactions.len() > MAX_BUNDLE_TRANSACTIONS {
+            return Err(anyhow!("Bundle can contain at most 5 transactions"));
+        }
+
+        let mut transactions = self.transactions.clone();
+        if let Some(blockhash) = &self.blockhash {
+            for tx in transactions.iter_mut().filter(|tx| !uses_durable_nonce(tx)) {
+                tx.message.set_recent_blockhash(blockhash.blockhash);
+                tx.signatures = vec![
+                    Signature::default();
+                    tx.message.header().num_required_signatures as usize
+                ];
+            }
+        }
+        let tip_lamports = self.tip_lamports(ctx)?;
+        if tip_lamports > 0 {
+            let tip_ix = tip_instruction_to(&self.tip_payer, &self.tip_account, tip_lamports);
+            let last = transactions.last_mut().expect("bundle is not empty");
+            append_tip_to_v0_transaction(last, &tip_ix, &self.lookup_tables)?;
+        }
+        for tx in &transactions {
+            check_transaction_size(tx)?;
+        }
+        Ok(transactions)
+    }
+
+    // build 之后用设置的签名者为所有交易签名，缺少任何签名时报错
+    pub async fn build_signed(&self, ctx: &TipContext) -> Result<Vec<VersionedTransaction>> {
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer set on bundle builder"))?;
+        let mut transactions = self.build(ctx)?;
+        sign_bundle(&mut transactions, signer.as_ref()).await?;
+        Ok(transactions)
+    }
+}
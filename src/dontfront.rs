@@ -0,0 +1,121 @@
+use crate::tip::ensure_account;
+use anyhow::{anyhow, Result};
+use solana_sdk::{
+    instruction::{AccountMeta, CompiledInstruction, Instruction},
+    message::{v0::LoadedAddresses, MessageHeader, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::str::FromStr;
+
+// 以该前缀开头的只读账户表示交易不可被抢跑：包含它的 bundle 必须把这笔交易放在第一位
+pub const DONT_FRONT_PREFIX: &str = "jitodontfront";
+pub const DONT_FRONT_ACCOUNT: &str = "jitodontfront111111111111111111111111111111";
+
+pub fn dont_front_account() -> Pubkey {
+    Pubkey::from_str(DONT_FRONT_ACCOUNT).expect("valid jitodontfront account")
+}
+
+pub fn is_dont_front_account(pubkey: &Pubkey) -> bool {
+    pubkey.to_string().starts_with(DONT_FRONT_PREFIX)
+}
+
+// 交易的静态账户中是否包含 jitodontfront 账户
+pub fn is_front_run_protected(tx: &VersionedTransaction) -> bool {
+    tx.message
+        .static_account_keys()
+        .iter()
+        .any(is_dont_front_account)
+}
+
+// 编译前把只读的 jitodontfront 账户加到指令上，已存在时不重复添加
+pub fn add_dont_front_account(ix: &mut Instruction, account: &Pubkey) {
+    if !ix.accounts.iter().any(|meta| meta.pubkey == *account) {
+        ix.accounts.push(AccountMeta::new_readonly(*account, false));
+    }
+}
+
+// 在已编译但未签名的交易中，把 jitodontfront 账户加到第 instruction_index 条指令上
+// 消息改变后原有签名失效，会被重置为默认值，需要调用方重新签名
+pub fn protect_transaction(
+    tx: &mut Transaction,
+    instruction_index: usize,
+    account: &Pubkey,
+) -> Result<()> {
+    let message = &mut tx.message;
+    add_account_to_instruction(
+        &mut message.header,
+        &mut message.account_keys,
+        &mut message.instructions,
+        instruction_index,
+        account,
+    )?;
+    tx.signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+    Ok(())
+}
+
+// VersionedTransaction 版本，同时支持 legacy 和 v0 消息
+pub fn protect_versioned_transaction(
+    tx: &mut VersionedTransaction,
+    instruction_index: usize,
+    account: &Pubkey,
+) -> Result<()> {
+    let header = match &mut tx.message {
+        VersionedMessage::Legacy(message) => {
+            add_account_to_instruction(
+                &mut message.header,
+                &mut message.account_keys,
+                &mut message.instructions,
+                instruction_index,
+                account,
+            )?;
+            message.header
+        }
+        VersionedMessage::V0(message) => {
+            add_account_to_instruction(
+                &mut message.header,
+                &mut message.account_keys,
+                &mut message.instructions,
+                instruction_index,
+                account,
+            )?;
+            message.header
+        }
+    };
+    tx.signatures = vec![Signature::default(); header.num_required_signatures as usize];
+    Ok(())
+}
+
+fn add_account_to_instruction(
+    header: &mut MessageHeader,
+    account_keys: &mut Vec<Pubkey>,
+    instructions: &mut [CompiledInstruction],
+    instruction_index: usize,
+    account: &Pubkey,
+) -> Result<()> {
+    if instruction_index >= instructions.len() {
+        return Err(anyhow!(
+            "Instruction {} out of range, message has {} instructions",
+            instruction_index,
+            instructions.len()
+        ));
+    }
+    ensure_account(
+        header,
+        account_keys,
+        instructions,
+        &LoadedAddresses::default(),
+        account,
+        false,
+    )?;
+    let index = account_keys
+        .iter()
+        .position(|key| key == account)
+        .ok_or_else(|| anyhow!("Account {} missing from message", account))? as u8;
+    let ix = &mut instructions[instruction_index];
+    if !ix.accounts.contains(&index) {
+        ix.accounts.push(index);
+    }
+    Ok(())
+}
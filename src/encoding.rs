@@ -0,0 +1,75 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "solana")]
+use solana_sdk::transaction::VersionedTransaction;
+use std::fmt;
+use std::str::FromStr;
+
+// 交易编码方式，base58 较慢且对大交易已不推荐，默认使用 base64
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    Base58,
+    #[default]
+    Base64,
+}
+
+impl Encoding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Base58 => "base58",
+            Encoding::Base64 => "base64",
+        }
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Base58 => bs58::encode(bytes).into_string(),
+            Encoding::Base64 => general_purpose::STANDARD.encode(bytes),
+        }
+    }
+
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>> {
+        match self {
+            Encoding::Base58 => bs58::decode(encoded)
+                .into_vec()
+                .map_err(|e| anyhow!("Invalid base58: {}", e)),
+            Encoding::Base64 => general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(|e| anyhow!("Invalid base64: {}", e)),
+        }
+    }
+
+    // 序列化并编码交易，Transaction 和 VersionedTransaction 均可
+    pub fn encode_transaction<T: Serialize>(&self, tx: &T) -> Result<String> {
+        let bytes = bincode::serialize(tx)
+            .map_err(|e| anyhow!("Failed to serialize transaction: {}", e))?;
+        Ok(self.encode(&bytes))
+    }
+
+    #[cfg(feature = "solana")]
+    pub fn decode_transaction(&self, encoded: &str) -> Result<VersionedTransaction> {
+        let bytes = self.decode(encoded)?;
+        bincode::deserialize(&bytes)
+            .map_err(|e| anyhow!("Failed to deserialize transaction: {}", e))
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "base58" => Ok(Encoding::Base58),
+            "base64" => Ok(Encoding::Base64),
+            other => Err(anyhow!("Unknown encoding: {}", other)),
+        }
+    }
+}
@@ -1,41 +1,42 @@
 use anyhow::{anyhow, Result};
+use crate::metrics::{self, Metrics, MetricsSink};
+#[cfg(feature = "solana")]
 use crate::proto::packet::Packet as ProtoPacket;
+#[cfg(feature = "solana")]
 use crate::proto::bundle::{Bundle};
+#[cfg(feature = "solana")]
+use crate::proto::searcher::SendBundleRequest;
 use crate::proto::searcher::{
-    GetTipAccountsRequest, GetTipAccountsResponse, SendBundleRequest,
+    GetRegionsRequest, GetRegionsResponse, GetTipAccountsRequest, GetTipAccountsResponse,
+    NextScheduledLeaderRequest, NextScheduledLeaderResponse,
 };
 use crate::proto::searcher::searcher_service_client::SearcherServiceClient;
+#[cfg(feature = "solana")]
 use solana_sdk::transaction::VersionedTransaction;
 use std::sync::Arc;
+use std::time::Instant;
 use tonic::transport::{Channel, Endpoint};
 use tokio::sync::Mutex;
 
 #[derive(Debug, Clone)]
 pub struct GrpcClient {
     client: Arc<Mutex<SearcherServiceClient<Channel>>>,
+    pub(crate) metrics: Metrics,
 }
 
 impl GrpcClient {
     pub async fn connect(addr: &str) -> Result<Self> {
         let endpoint = if addr.contains("https") {
-            Endpoint::from_shared(addr.to_string())
-                .expect("invalid url")
-                .tls_config(tonic::transport::ClientTlsConfig::new())
-                .expect("tls config error")
-                .connect_timeout(std::time::Duration::from_secs(10))
-                .tcp_keepalive(Some(std::time::Duration::from_secs(60)))
-                .http2_keep_alive_interval(std::time::Duration::from_secs(30))
-                .keep_alive_timeout(std::time::Duration::from_secs(20))
-                .keep_alive_while_idle(true)
+            Self::tls_endpoint(addr)?
         } else {
-            Endpoint::from_shared(addr.to_string())
-                .expect("invalid url")
-                .connect_timeout(std::time::Duration::from_secs(10))
-                .tcp_keepalive(Some(std::time::Duration::from_secs(60)))
-                .http2_keep_alive_interval(std::time::Duration::from_secs(30))
-                .keep_alive_timeout(std::time::Duration::from_secs(20))
-                .keep_alive_while_idle(true)
+            Self::endpoint(addr)?
         };
+        let endpoint = endpoint
+            .connect_timeout(std::time::Duration::from_secs(10))
+            .tcp_keepalive(Some(std::time::Duration::from_secs(60)))
+            .http2_keep_alive_interval(std::time::Duration::from_secs(30))
+            .keep_alive_timeout(std::time::Duration::from_secs(20))
+            .keep_alive_while_idle(true);
 
         let channel = endpoint
             .connect()
@@ -44,22 +45,99 @@ impl GrpcClient {
 
         let client = SearcherServiceClient::new(channel);
         Ok(Self { 
-            client: Arc::new(Mutex::new(client))
+            client: Arc::new(Mutex::new(client)),
+            metrics: Metrics::default(),
         })
     }
 
+    // 设置指标输出，每次 gRPC 调用记录耗时和结果分类
+    pub fn with_metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Metrics::new(sink);
+        self
+    }
+
+    fn record<T>(&self, method: &str, result: &Result<T, tonic::Status>, started: Instant) {
+        let outcome = match result {
+            Ok(_) => metrics::OUTCOME_OK,
+            Err(status) => match status.code() {
+                tonic::Code::ResourceExhausted => metrics::OUTCOME_RATE_LIMITED,
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => {
+                    metrics::OUTCOME_TRANSPORT_ERROR
+                }
+                _ => metrics::OUTCOME_GRPC_ERROR,
+            },
+        };
+        self.metrics
+            .record_request("grpc", method, outcome, started.elapsed());
+    }
+
+    // 空地址或无法解析的地址返回错误
+    fn endpoint(addr: &str) -> Result<Endpoint> {
+        if addr.trim().is_empty() {
+            return Err(anyhow!("gRPC url is empty"));
+        }
+        Endpoint::from_shared(addr.to_string()).map_err(|e| anyhow!("Invalid url {}: {}", addr, e))
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    fn tls_endpoint(addr: &str) -> Result<Endpoint> {
+        Self::endpoint(addr)?
+            .tls_config(tonic::transport::ClientTlsConfig::new())
+            .map_err(|e| anyhow!("TLS config error: {}", e))
+    }
+
+    // 未启用 TLS feature 时只能连接 http 地址
+    #[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+    fn tls_endpoint(addr: &str) -> Result<Endpoint> {
+        Err(anyhow!(
+            "Cannot connect to {}: enable the `native-tls` or `rustls` feature for TLS",
+            addr
+        ))
+    }
+
     pub async fn get_tip_accounts(&self) -> Result<GetTipAccountsResponse> {
         let request = tonic::Request::new(GetTipAccountsRequest {});
         let mut client = self.client.lock().await;
-        match client.get_tip_accounts(request).await {
+        let started = Instant::now();
+        let result = client.get_tip_accounts(request).await;
+        self.record("GetTipAccounts", &result, started);
+        match result {
             Ok(response) => Ok(response.into_inner()),
             Err(e) => Err(anyhow!("Failed to get tip accounts: {}", e)),
         }
     }
 
+    pub async fn get_next_scheduled_leader(
+        &self,
+        regions: Vec<String>,
+    ) -> Result<NextScheduledLeaderResponse> {
+        let request = tonic::Request::new(NextScheduledLeaderRequest { regions });
+        let mut client = self.client.lock().await;
+        let started = Instant::now();
+        let result = client.get_next_scheduled_leader(request).await;
+        self.record("GetNextScheduledLeader", &result, started);
+        match result {
+            Ok(response) => Ok(response.into_inner()),
+            Err(e) => Err(anyhow!("Failed to get next scheduled leader: {}", e)),
+        }
+    }
+
+    pub async fn get_regions(&self) -> Result<GetRegionsResponse> {
+        let request = tonic::Request::new(GetRegionsRequest {});
+        let mut client = self.client.lock().await;
+        let started = Instant::now();
+        let result = client.get_regions(request).await;
+        self.record("GetRegions", &result, started);
+        match result {
+            Ok(response) => Ok(response.into_inner()),
+            Err(e) => Err(anyhow!("Failed to get regions: {}", e)),
+        }
+    }
+
+    #[cfg(feature = "solana")]
     pub async fn send_bundle(&self, transactions: Vec<VersionedTransaction>) -> Result<String> {
-        println!("准备发送 bundle，交易数量: {}", transactions.len());
-        
+        tracing::debug!(transactions = transactions.len(), "sending bundle");
+
         let request = tonic::Request::new(SendBundleRequest {
             bundle: Some(Bundle {
                 header: None,
@@ -69,22 +147,25 @@ impl GrpcClient {
                     .collect(),
             }),
         });
-        
-        println!("已创建 gRPC 请求");
-        match self.client.lock().await.send_bundle(request).await {
+
+        let started = Instant::now();
+        let result = self.client.lock().await.send_bundle(request).await;
+        self.record("SendBundle", &result, started);
+        match result {
             Ok(response) => {
                 let uuid = response.into_inner().uuid;
-                println!("Bundle 发送成功，UUID: {}", uuid);
+                tracing::debug!(%uuid, "bundle sent");
                 Ok(uuid)
             },
             Err(e) => {
-                println!("Bundle 发送失败: {}", e);
+                tracing::warn!(%e, "failed to send bundle");
                 Err(anyhow!("Failed to send bundle: {}", e))
             },
         }
     }
 }
 
+#[cfg(feature = "solana")]
 fn proto_packet_from_versioned_tx(tx: &VersionedTransaction) -> ProtoPacket {
     ProtoPacket {
         data: bincode::serialize(&tx).unwrap(),
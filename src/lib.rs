@@ -1,43 +1,211 @@
 // 导入必要的外部依赖
 use anyhow::{anyhow, Result};
 use rand::seq::SliceRandom;
-use reqwest::Client;
 use serde_json::{json, Value};
+#[cfg(feature = "solana")]
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, transaction::VersionedTransaction};
 use std::fmt;
-
-// 定义 protobuf 生成的模块
+#[cfg(feature = "solana")]
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::Instrument;
+
+// 定义 protobuf 生成的模块，代码提交在 src/proto 下；启用 regenerate-protos 时使用 build.rs 在 OUT_DIR 中生成的代码
+#[cfg(feature = "grpc")]
 pub mod proto {
+    #[cfg(not(feature = "regenerate-protos"))]
+    macro_rules! include_generated {
+        ($package:literal) => {
+            include!(concat!("proto/", $package, ".rs"));
+        };
+    }
+
+    #[cfg(feature = "regenerate-protos")]
+    macro_rules! include_generated {
+        ($package:literal) => {
+            include!(concat!(env!("OUT_DIR"), "/", $package, ".rs"));
+        };
+    }
+
     pub mod block_engine {
-        tonic::include_proto!("block_engine");
+        include_generated!("block_engine");
     }
 
     pub mod bundle {
-        tonic::include_proto!("bundle");
+        include_generated!("bundle");
     }
 
     pub mod packet {
-        tonic::include_proto!("packet");
+        include_generated!("packet");
     }
 
     pub mod shared {
-        tonic::include_proto!("shared");
+        include_generated!("shared");
     }
 
     pub mod searcher {
-        tonic::include_proto!("searcher");
+        include_generated!("searcher");
     }
 }
 
 // 导出 gRPC 模块
+#[cfg(feature = "grpc")]
 pub mod grpc;
+#[cfg(feature = "grpc")]
 pub use grpc::GrpcClient;
 
+// 请求与 bundle 指标
+pub mod metrics;
+pub use metrics::{InMemoryMetrics, Metrics, MetricsSink};
+
+// 交易编码
+pub mod encoding;
+pub use encoding::Encoding;
+
+// v0 交易与地址查找表
+#[cfg(feature = "solana")]
+pub mod versioned;
+#[cfg(feature = "solana")]
+pub use versioned::{
+    build_v0_transaction, check_transaction_size, compile_v0_message, transaction_size,
+    unsigned_v0_transaction,
+};
+
+// tip 指令相关工具
+#[cfg(feature = "solana")]
+pub mod tip;
+#[cfg(feature = "solana")]
+pub use tip::{
+    append_tip_to_transaction, append_tip_to_v0_transaction, append_tip_to_versioned_transaction,
+    tip_instruction_to,
+};
+
+// tip floor（落地 tip 分位数）客户端
+pub mod tip_floor;
+pub use tip_floor::{TipFloor, TipFloorData, TipPercentile};
+
+// 本地 mock tip floor 服务，用于离线测试
+#[cfg(feature = "test-util")]
+pub mod tip_floor_mock;
+#[cfg(feature = "test-util")]
+pub use tip_floor_mock::MockTipFloorServer;
+
+// tip 出价策略
+pub mod tip_strategy;
+pub use tip_strategy::{EscalatingTip, FixedTip, PercentileTip, ProfitShareTip, TipContext, TipStrategy};
+
+// 最新 blockhash 的后台刷新
+#[cfg(feature = "solana")]
+pub mod blockhash;
+#[cfg(feature = "solana")]
+pub use blockhash::{BlockhashManager, LatestBlockhash};
+
+// durable nonce 交易
+#[cfg(feature = "solana")]
+pub mod nonce;
+#[cfg(feature = "solana")]
+pub use nonce::{nonced_v0_transaction, NonceClient, NonceInfo};
+
+// jitodontfront 防抢跑保护
+#[cfg(feature = "solana")]
+pub mod dontfront;
+#[cfg(feature = "solana")]
+pub use dontfront::{
+    add_dont_front_account, dont_front_account, is_front_run_protected, protect_transaction,
+    protect_versioned_transaction,
+};
+
+// bundle 签名
+#[cfg(feature = "solana")]
+pub mod signer;
+#[cfg(feature = "solana")]
+pub use signer::{sign_bundle, BundleSigner, RemoteSigner};
+
+// bundle 构建
+#[cfg(feature = "solana")]
+pub mod bundle;
+#[cfg(feature = "solana")]
+pub use bundle::BundleBuilder;
+
+// bundle 预检
+#[cfg(feature = "solana")]
+pub mod validation;
+#[cfg(feature = "solana")]
+pub use validation::{BundleValidationError, BundleValidator, TipPlacement};
+
+// simulateBundle 模拟
+#[cfg(feature = "solana")]
+pub mod simulation;
+#[cfg(feature = "solana")]
+pub use simulation::{BundleSimulator, SimulateBundleConfig, SimulateBundleResult};
+
+// bundle 状态跟踪
+pub mod tracker;
+pub use tracker::{BundleState, BundleTracker, BundleTransition};
+
+// JSON-RPC 传输层
+pub mod transport;
+pub use transport::{JsonRpcTransport, ReqwestTransport, TransportFuture, TransportResponse};
+
+// JSON-RPC 批量请求
+pub mod batch;
+pub use batch::{
+    BatchCall, BatchResults, BundleStatus, InflightBundleStatus, JsonRpcBatch,
+};
+
+// 重复发送 bundle 直到落地
+#[cfg(feature = "solana")]
+pub mod resubmit;
+#[cfg(feature = "solana")]
+pub use resubmit::{SubmitAttempt, SubmitOptions, SubmitOutcome, SubmitReport};
+
+// 按 Jito leader 排期发送 bundle
+#[cfg(all(feature = "grpc", feature = "solana"))]
+pub mod scheduler;
+#[cfg(all(feature = "grpc", feature = "solana"))]
+pub use scheduler::{BundleScheduler, LeaderWindow, ScheduleEvent, ScheduledBundle};
+
+// 同步客户端
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+// 本地 mock Block Engine，用于离线测试，需要 test-util、grpc 和 solana feature
+#[cfg(all(feature = "test-util", feature = "grpc", feature = "solana"))]
+pub mod mock;
+#[cfg(all(feature = "test-util", feature = "grpc", feature = "solana"))]
+pub use mock::{MockBlockEngine, MockBundleOutcome, MockResponse};
+
+// 进程内唯一的 JSON-RPC 请求 id，多个 SDK 实例共用，便于经过共享代理时关联响应
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+pub(crate) fn next_request_id() -> u64 {
+    NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+// 校验响应 id 与请求一致；无法解析请求时服务端可能返回 id 为 null 的 error
+pub(crate) fn check_response_id(body: &Value, id: u64) -> Result<()> {
+    match body.get("id") {
+        Some(Value::Number(n)) if n.as_u64() == Some(id) => Ok(()),
+        None | Some(Value::Null) if body.get("error").is_some() => Ok(()),
+        other => Err(anyhow!(
+            "JSON-RPC response id mismatch: sent {}, received {}",
+            id,
+            other.cloned().unwrap_or(Value::Null)
+        )),
+    }
+}
+
 // JSON-RPC SDK 实现
 pub struct JitoJsonRpcSDK {
     base_url: String,                // API 基础 URL
     uuid: Option<String>,            // 可选的 UUID
-    client: Client,                  // HTTP 客户端
+    transport: Arc<dyn JsonRpcTransport>, // HTTP 传输层，默认 reqwest
+    #[cfg(feature = "grpc")]
     grpc_url: Option<String>,        // gRPC URL
+    encoding: Encoding,              // 交易编码方式
+    metrics: Metrics,                // 请求指标
 }
 
 #[derive(Debug)]
@@ -61,50 +229,141 @@ impl JitoJsonRpcSDK {
         Self {
             base_url: base_url.to_string(),
             uuid,
-            client: Client::new(),
+            transport: Arc::new(ReqwestTransport::default()),
+            #[cfg(feature = "grpc")]
             grpc_url: None,
+            encoding: Encoding::default(),
+            metrics: Metrics::default(),
         }
     }
 
+    // 设置指标输出，每次 JSON-RPC 请求记录耗时和结果分类
+    pub fn with_metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Metrics::new(sink);
+        self
+    }
+
+    // 替换 HTTP 传输层，例如测试用的内存实现
+    pub fn with_transport(mut self, transport: Arc<dyn JsonRpcTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    // 设置交易编码方式，默认 base64
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
 
-    // 发送 JSON-RPC 请求的通用方法
+    // 设置 get_tip_accounts_grpc 使用的 gRPC 地址
+    #[cfg(feature = "grpc")]
+    pub fn with_grpc_url(mut self, grpc_url: &str) -> Self {
+        self.grpc_url = Some(grpc_url.to_string());
+        self
+    }
+
+    // 为 [payload] / [payload, options] 形式的参数补上 encoding 选项，已指定时保持不变
+    fn with_encoding_option(&self, params: Value) -> Value {
+        match params {
+            Value::Array(mut items) => {
+                if items.len() == 1 {
+                    items.push(json!({}));
+                }
+                if let Some(Value::Object(options)) = items.get_mut(1) {
+                    options
+                        .entry("encoding")
+                        .or_insert_with(|| json!(self.encoding.as_str()));
+                }
+                Value::Array(items)
+            }
+            other => other,
+        }
+    }
+
+    // /bundles 接口路径，带上 uuid 查询参数
+    fn bundles_endpoint(&self) -> String {
+        match &self.uuid {
+            Some(uuid) => format!("/bundles?uuid={}", uuid),
+            None => "/bundles".to_string(),
+        }
+    }
+
+    // 发送 JSON-RPC 请求的通用方法，每个请求使用唯一 id 并校验响应 id，错误信息中带有 id
     async fn send_request(
         &self,
         endpoint: &str,
         method: &str,
         params: Option<Value>,
-    ) -> Result<Value, reqwest::Error> {
+    ) -> Result<Value> {
+        let id = next_request_id();
+        let span = tracing::debug_span!("jsonrpc_request", method, id, endpoint);
+        self.send_request_with_id(endpoint, method, params, id)
+            .instrument(span)
+            .await
+            .map_err(|e| anyhow!("Request error (id {}): {}", id, e))
+    }
+
+    async fn send_request_with_id(
+        &self,
+        endpoint: &str,
+        method: &str,
+        params: Option<Value>,
+        id: u64,
+    ) -> Result<Value> {
         let url = format!("{}{}", self.base_url, endpoint);
 
         let data = json!({
             "jsonrpc": "2.0",
-            "id": 1,
+            "id": id,
             "method": method,
             "params": params.unwrap_or(json!([]))
         });
 
-        println!("Sending request to: {}", url);
-        println!(
-            "Request body: {}",
-            serde_json::to_string_pretty(&data).unwrap()
-        );
+        tracing::debug!(%url, "sending request");
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .json(&data)
-            .send()
-            .await?;
-
-        let status = response.status();
-        println!("Response status: {}", status);
+        let started = Instant::now();
+        let response = match self.transport.post(&url, &data).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.metrics.record_request(
+                    "json_rpc",
+                    method,
+                    metrics::OUTCOME_TRANSPORT_ERROR,
+                    started.elapsed(),
+                );
+                return Err(e);
+            }
+        };
 
-        let body = response.json::<Value>().await?;
-        println!(
-            "Response body: {}",
-            serde_json::to_string_pretty(&body).unwrap()
-        );
+        tracing::debug!(status = response.status, "received response");
+
+        let id_check = response.body.as_ref().map(|body| check_response_id(body, id));
+        let outcome = metrics::json_rpc_outcome(&response, !matches!(id_check, Some(Err(_))));
+        self.metrics
+            .record_request("json_rpc", method, outcome, started.elapsed());
+
+        let body = response.body.ok_or_else(|| {
+            anyhow!(
+                "Failed to decode response body (HTTP {})",
+                response.status
+            )
+        })?;
+        if let Some(Err(e)) = id_check {
+            tracing::warn!(%e, "response id mismatch");
+            return Err(e);
+        }
 
         Ok(body)
     }
@@ -131,33 +390,54 @@ impl JitoJsonRpcSDK {
             .map(String::from)
     }
 
+    // 获取随机的 tip account，并解析为 Pubkey
+    #[cfg(feature = "solana")]
+    pub async fn random_tip_pubkey(&self) -> Result<Pubkey> {
+        let tip_account = self.get_random_tip_account().await?;
+        Pubkey::from_str(&tip_account)
+            .map_err(|e| anyhow!("Invalid tip account {}: {}", tip_account, e))
+    }
+
+    // 构造向随机 tip account 转账的指令
+    #[cfg(feature = "solana")]
+    pub async fn tip_instruction(&self, payer: &Pubkey, lamports: u64) -> Result<Instruction> {
+        let tip_account = self.random_tip_pubkey().await?;
+        Ok(tip_instruction_to(payer, &tip_account, lamports))
+    }
+
     pub async fn get_bundle_statuses(&self, bundle_uuids: Vec<String>) -> Result<Value> {
-        let endpoint = if let Some(uuid) = &self.uuid {
-            format!("/bundles?uuid={}", uuid)
-        } else {
-            "/bundles".to_string()
-        };
+        let endpoint = self.bundles_endpoint();
 
         let params = json!([bundle_uuids]);
 
         self.send_request(&endpoint, "getBundleStatuses", Some(params))
             .await
-            .map_err(|e| anyhow!("Request error: {}", e))
     }
 
-    pub async fn get_tip_accounts(&self) -> Result<Value, reqwest::Error> {
-        let endpoint = if let Some(uuid) = &self.uuid {
-            format!("/bundles?uuid={}", uuid)
-        } else {
-            "/bundles".to_string()
-        };
+    pub async fn get_in_flight_bundle_statuses(&self, bundle_uuids: Vec<String>) -> Result<Value> {
+        let endpoint = self.bundles_endpoint();
+
+        let params = json!([bundle_uuids]);
+
+        self.send_request(&endpoint, "getInflightBundleStatuses", Some(params))
+            .await
+    }
+
+    pub async fn get_tip_accounts(&self) -> Result<Value> {
+        let endpoint = self.bundles_endpoint();
 
         self.send_request(&endpoint, "getTipAccounts", None).await
     }
 
     // 新增 GRPC 版本的 get_tip_accounts 方法
+    #[cfg(feature = "grpc")]
     pub async fn get_tip_accounts_grpc(&self) -> Result<Value> {
-        let grpc_client = GrpcClient::connect(&self.grpc_url.clone().unwrap_or_default()).await?;
+        let grpc_url = self
+            .grpc_url
+            .as_deref()
+            .ok_or_else(|| anyhow!("gRPC url is not set, use with_grpc_url"))?;
+        let mut grpc_client = GrpcClient::connect(grpc_url).await?;
+        grpc_client.metrics = self.metrics.clone();
         let response = grpc_client.get_tip_accounts().await?;
 
         Ok(json!({
@@ -167,6 +447,61 @@ impl JitoJsonRpcSDK {
         }))
     }
 
+    // 编码并发送单笔交易
+    #[cfg(feature = "solana")]
+    pub async fn send_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+        bundle_only: bool,
+    ) -> Result<Value> {
+        let tx = self.encoding.encode_transaction(transaction)?;
+        self.send_txn(Some(json!({ "tx": tx })), bundle_only).await
+    }
+
+    // 发送单笔交易，params 为 {"tx": 编码后的交易, "skipPreflight": bool} 或原始参数数组
+    pub async fn send_txn(&self, params: Option<Value>, bundle_only: bool) -> Result<Value> {
+        let mut query_params = Vec::new();
+        if bundle_only {
+            query_params.push("bundleOnly=true".to_string());
+        }
+        if let Some(uuid) = &self.uuid {
+            query_params.push(format!("uuid={}", uuid));
+        }
+        let endpoint = if query_params.is_empty() {
+            "/transactions".to_string()
+        } else {
+            format!("/transactions?{}", query_params.join("&"))
+        };
+
+        let params = match params {
+            Some(Value::Object(map)) => {
+                let tx = map
+                    .get("tx")
+                    .and_then(|tx| tx.as_str())
+                    .ok_or_else(|| anyhow!("Missing tx in params"))?;
+                let skip_preflight = map
+                    .get("skipPreflight")
+                    .and_then(|s| s.as_bool())
+                    .unwrap_or(false);
+                json!([tx, { "encoding": self.encoding.as_str(), "skipPreflight": skip_preflight }])
+            }
+            Some(value) => self.with_encoding_option(value),
+            None => return Err(anyhow!("Missing transaction params")),
+        };
+
+        let response = self
+            .send_request(&endpoint, "sendTransaction", Some(params))
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            let error_msg = error.get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("未知错误");
+            return Err(anyhow!("交易发送失败: {}", error_msg));
+        }
+        Ok(response)
+    }
+
     pub fn prettify(value: Value) -> PrettyJsonValue {
         PrettyJsonValue(value)
     }
@@ -174,7 +509,7 @@ impl JitoJsonRpcSDK {
     // 校验 bundle 参数
     fn validate_bundle_params(params: &Option<Value>) -> Result<()> {
         match params {
-            Some(Value::Array(outer_array)) if outer_array.len() >= 1 => {
+            Some(Value::Array(outer_array)) if !outer_array.is_empty() => {
                 if let Some(serialized_txs) = outer_array[0].as_array() {
                     if serialized_txs.is_empty() {
                         return Err(anyhow!("Bundle must contain at least one transaction"));
@@ -207,6 +542,7 @@ impl JitoJsonRpcSDK {
 
         // 参数校验
         Self::validate_bundle_params(&params)?;
+        let params = params.map(|params| self.with_encoding_option(params));
 
         match self.send_request(&endpoint, "sendBundle", params).await {
             Ok(response) => {
@@ -217,15 +553,29 @@ impl JitoJsonRpcSDK {
                         .unwrap_or("未知错误");
                     return Err(anyhow!("Bundle 发送失败: {}", error_msg));
                 }
-                println!("Bundle 响应: {}", serde_json::to_string_pretty(&response).unwrap());
+                tracing::debug!(bundle_id = %response["result"], "bundle sent");
                 Ok(response)
             }
             Err(e) => {
-                println!("Bundle 发送失败: {:?}", e);
+                tracing::warn!(%e, "failed to send bundle");
                 Err(anyhow!("Failed to send bundle: {}", e))
             }
         }
     }
 
+    // 编码并发送交易包
+    #[cfg(feature = "solana")]
+    pub async fn send_bundle_transactions(
+        &self,
+        transactions: &[VersionedTransaction],
+        uuid: Option<&str>,
+    ) -> Result<Value> {
+        let encoded = transactions
+            .iter()
+            .map(|tx| self.encoding.encode_transaction(tx))
+            .collect::<Result<Vec<_>>>()?;
+        self.send_bundle(Some(json!([encoded])), uuid).await
+    }
+
   
 }
@@ -0,0 +1,217 @@
+use crate::transport::TransportResponse;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// 指标名称
+pub const REQUESTS_TOTAL: &str = "jito_requests_total"; // labels: transport, method, outcome
+pub const REQUEST_DURATION_SECONDS: &str = "jito_request_duration_seconds"; // labels: transport, method
+pub const BUNDLES_TRACKED_TOTAL: &str = "jito_bundles_tracked_total";
+pub const BUNDLE_RESULTS_TOTAL: &str = "jito_bundle_results_total"; // labels: state
+pub const BUNDLE_LANDING_SECONDS: &str = "jito_bundle_landing_seconds";
+pub const TIP_LAMPORTS_TOTAL: &str = "jito_tip_lamports_total";
+
+// 请求结果分类，作为 outcome label
+pub const OUTCOME_OK: &str = "ok";
+pub const OUTCOME_RATE_LIMITED: &str = "rate_limited"; // HTTP 429 / RESOURCE_EXHAUSTED
+pub const OUTCOME_RPC_ERROR: &str = "rpc_error"; // JSON-RPC 响应中的 error
+pub const OUTCOME_HTTP_ERROR: &str = "http_error"; // 其他非 2xx 状态码
+pub const OUTCOME_TRANSPORT_ERROR: &str = "transport_error"; // 连接失败、超时
+pub const OUTCOME_DECODE_ERROR: &str = "decode_error"; // 响应无法解析
+pub const OUTCOME_ID_MISMATCH: &str = "id_mismatch"; // JSON-RPC 响应 id 与请求不一致
+pub const OUTCOME_GRPC_ERROR: &str = "grpc_error"; // 其他 gRPC 状态码
+
+// 按 HTTP 状态、响应体和 id 校验结果对一次 JSON-RPC 调用分类
+pub(crate) fn json_rpc_outcome(response: &TransportResponse, id_matches: bool) -> &'static str {
+    match &response.body {
+        _ if response.is_rate_limited() => OUTCOME_RATE_LIMITED,
+        Some(_) if !id_matches => OUTCOME_ID_MISMATCH,
+        Some(body) if body.get("error").is_some() => OUTCOME_RPC_ERROR,
+        _ if !response.is_success() => OUTCOME_HTTP_ERROR,
+        Some(_) => OUTCOME_OK,
+        None => OUTCOME_DECODE_ERROR,
+    }
+}
+
+pub type Labels = [(&'static str, String)];
+
+// 指标输出接口，可接入 Prometheus、StatsD 等；启用 metrics feature 时可用 MetricsFacade
+pub trait MetricsSink: Send + Sync {
+    fn increment_counter(&self, name: &'static str, labels: &Labels, value: u64);
+    fn record_histogram(&self, name: &'static str, labels: &Labels, value: f64);
+}
+
+// 客户端持有的指标句柄，未设置 sink 时不做任何事
+#[derive(Clone, Default)]
+pub struct Metrics(Option<Arc<dyn MetricsSink>>);
+
+impl Metrics {
+    pub fn new(sink: Arc<dyn MetricsSink>) -> Self {
+        Self(Some(sink))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    pub fn increment_counter(&self, name: &'static str, labels: &Labels, value: u64) {
+        if let Some(sink) = &self.0 {
+            sink.increment_counter(name, labels, value);
+        }
+    }
+
+    pub fn record_histogram(&self, name: &'static str, labels: &Labels, value: f64) {
+        if let Some(sink) = &self.0 {
+            sink.record_histogram(name, labels, value);
+        }
+    }
+
+    // 记录一次 JSON-RPC / gRPC 调用
+    pub fn record_request(
+        &self,
+        transport: &'static str,
+        method: &str,
+        outcome: &'static str,
+        duration: Duration,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.increment_counter(
+            REQUESTS_TOTAL,
+            &[
+                ("transport", transport.to_string()),
+                ("method", method.to_string()),
+                ("outcome", outcome.to_string()),
+            ],
+            1,
+        );
+        self.record_histogram(
+            REQUEST_DURATION_SECONDS,
+            &[
+                ("transport", transport.to_string()),
+                ("method", method.to_string()),
+            ],
+            duration.as_secs_f64(),
+        );
+    }
+}
+
+impl fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metrics")
+            .field("enabled", &self.is_enabled())
+            .finish()
+    }
+}
+
+impl<S: MetricsSink + 'static> From<Arc<S>> for Metrics {
+    fn from(sink: Arc<S>) -> Self {
+        Self(Some(sink))
+    }
+}
+
+// 内存中的指标记录，用于测试断言
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics {
+    counters: Mutex<HashMap<MetricKey, u64>>,
+    histograms: Mutex<HashMap<MetricKey, Vec<f64>>>,
+}
+
+type MetricKey = (String, Vec<(String, String)>);
+
+// labels 排序后作为 key，与传入顺序无关
+fn metric_key<'a>(name: &str, labels: impl Iterator<Item = (&'a str, &'a str)>) -> MetricKey {
+    let mut labels = labels
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect::<Vec<_>>();
+    labels.sort();
+    (name.to_string(), labels)
+}
+
+fn sink_key(name: &str, labels: &Labels) -> MetricKey {
+    metric_key(name, labels.iter().map(|(k, v)| (*k, v.as_str())))
+}
+
+impl InMemoryMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 精确匹配 labels（与顺序无关）的计数
+    pub fn counter(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        let key = metric_key(name, labels.iter().copied());
+        self.counters
+            .lock()
+            .unwrap()
+            .get(&key)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // 名称相同的所有计数之和，不区分 labels
+    pub fn counter_total(&self, name: &str) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|((n, _), _)| n == name)
+            .map(|(_, v)| *v)
+            .sum()
+    }
+
+    pub fn histogram(&self, name: &str, labels: &[(&str, &str)]) -> Vec<f64> {
+        let key = metric_key(name, labels.iter().copied());
+        self.histograms
+            .lock()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl MetricsSink for InMemoryMetrics {
+    fn increment_counter(&self, name: &'static str, labels: &Labels, value: u64) {
+        *self
+            .counters
+            .lock()
+            .unwrap()
+            .entry(sink_key(name, labels))
+            .or_insert(0) += value;
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: &Labels, value: f64) {
+        self.histograms
+            .lock()
+            .unwrap()
+            .entry(sink_key(name, labels))
+            .or_default()
+            .push(value);
+    }
+}
+
+// 转发到 metrics crate 的全局 recorder
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetricsFacade;
+
+#[cfg(feature = "metrics")]
+impl MetricsSink for MetricsFacade {
+    fn increment_counter(&self, name: &'static str, labels: &Labels, value: u64) {
+        ::metrics::counter!(name, facade_labels(labels)).increment(value);
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: &Labels, value: f64) {
+        ::metrics::histogram!(name, facade_labels(labels)).record(value);
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn facade_labels(labels: &Labels) -> Vec<::metrics::Label> {
+    labels
+        .iter()
+        .map(|(k, v)| ::metrics::Label::new(*k, v.clone()))
+        .collect()
+}
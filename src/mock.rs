@@ -0,0 +1,787 @@
+use crate::proto::bundle::{bundle_result, Accepted, BundleResult};
+use crate::proto::searcher::searcher_service_server::{SearcherService, SearcherServiceServer};
+use crate::proto::searcher::{
+    ConnectedLeadersRegionedRequest, ConnectedLeadersRegionedResponse, ConnectedLeadersRequest,
+    ConnectedLeadersResponse, GetRegionsRequest, GetRegionsResponse, GetTipAccountsRequest,
+    GetTipAccountsResponse, NextScheduledLeaderRequest, NextScheduledLeaderResponse,
+    SendBundleRequest, SendBundleResponse, SubscribeBundleResultsRequest,
+};
+use crate::encoding::Encoding;
+use crate::scheduler::BLOCKHASH_VALID_SLOTS;
+use anyhow::Result;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, StatusCode};
+use serde_json::{json, Value};
+use base64::{engine::general_purpose, Engine as _};
+use solana_sdk::{
+    hash::{hashv, Hash},
+    nonce::state::{DurableNonce, State as NonceState, Versions},
+    pubkey::Pubkey,
+    system_program,
+    transaction::VersionedTransaction,
+};
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
+use tonic::{transport::Server, Status};
+
+// 下一次请求的脚本化响应，按顺序消费，JSON-RPC 和 gRPC 共用
+#[derive(Debug, Clone)]
+pub enum MockResponse {
+    RateLimited,                           // HTTP 429 / RESOURCE_EXHAUSTED
+    Reject { code: i64, message: String }, // JSON-RPC error / INVALID_ARGUMENT
+    Delay(Duration),                       // 延迟后正常处理
+    MismatchedId,                          // JSON-RPC 响应使用错误的 id，gRPC 忽略
+}
+
+// 提交的 bundle 最终的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockBundleOutcome {
+    Land,
+    Fail,
+}
+
+// mock 收到的请求，用于测试断言
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub path: String,          // HTTP 路径或 gRPC 方法路径
+    pub query: Option<String>, // URL 查询参数
+    pub method: String,        // JSON-RPC 方法名或 gRPC 方法名
+    pub body: Value,           // JSON-RPC 请求体，gRPC 请求为摘要
+}
+
+#[derive(Debug, Clone)]
+struct MockBundle {
+    transactions: Vec<String>,
+    submitted_at: Instant,
+    landing_delay: Duration,
+    outcome: MockBundleOutcome,
+}
+
+enum BundleState {
+    Pending,
+    Failed,
+    Landed { slot: u64, finalized: bool },
+}
+
+struct MockState {
+    started_at: Instant,
+    start_slot: u64,
+    slot_duration: Duration,
+    tip_accounts: Vec<String>,
+    regions: Vec<String>,
+    leader_offset: u64,
+    leader_identity: String,
+    landing_delay: Duration,
+    finalization_delay: Duration,
+    outcome: MockBundleOutcome,
+    batch_supported: bool,
+    nonce_accounts: HashMap<String, Vec<u8>>, // nonce 账户数据
+    simulation_failure: Option<usize>,         // simulateBundle 中执行失败的交易
+    scripted: VecDeque<MockResponse>,
+    requests: Vec<RecordedRequest>,
+    bundles: HashMap<String, MockBundle>,
+    subscribers: Vec<mpsc::Sender<Result<BundleResult, Status>>>,
+}
+
+impl MockState {
+    fn slot_at(&self, at: Instant) -> u64 {
+        let elapsed = at.saturating_duration_since(self.started_at);
+        self.start_slot + (elapsed.as_millis() / self.slot_duration.as_millis().max(1)) as u64
+    }
+
+    fn current_slot(&self) -> u64 {
+        self.slot_at(Instant::now())
+    }
+
+    fn bundle_state(&self, bundle: &MockBundle) -> BundleState {
+        let landed_at = bundle.submitted_at + bundle.landing_delay;
+        let now = Instant::now();
+        if now < landed_at {
+            return BundleState::Pending;
+        }
+        match bundle.outcome {
+            MockBundleOutcome::Fail => BundleState::Failed,
+            MockBundleOutcome::Land => BundleState::Landed {
+                slot: self.slot_at(landed_at),
+                finalized: now >= landed_at + self.finalization_delay,
+            },
+        }
+    }
+
+    // 记录一个新 bundle，返回 bundle id；重复提交的 bundle 保持首次提交的状态
+    fn submit_bundle(&mut self, transactions: Vec<String>, raw: &[&[u8]]) -> String {
+        let id = hashv(raw)
+            .to_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        if self.bundles.contains_key(&id) {
+            return id;
+        }
+        let bundle = MockBundle {
+            transactions,
+            submitted_at: Instant::now(),
+            landing_delay: self.landing_delay,
+            outcome: self.outcome,
+        };
+        self.notify_subscribers(&id, &bundle);
+        self.bundles.insert(id.clone(), bundle);
+        id
+    }
+
+    // 落地后通过 SubscribeBundleResults 推送结果
+    fn notify_subscribers(&mut self, id: &str, bundle: &MockBundle) {
+        self.subscribers.retain(|tx| !tx.is_closed());
+        if bundle.outcome != MockBundleOutcome::Land || self.subscribers.is_empty() {
+            return;
+        }
+        let result = BundleResult {
+            bundle_id: id.to_string(),
+            result: Some(bundle_result::Result::Accepted(Accepted {
+                slot: self.slot_at(bundle.submitted_at + bundle.landing_delay),
+                validator_identity: self.leader_identity.clone(),
+            })),
+        };
+        let subscribers = self.subscribers.clone();
+        let delay = bundle.landing_delay;
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            for tx in subscribers {
+                let _ = tx.send(Ok(result.clone())).await;
+            }
+        });
+    }
+}
+
+type SharedState = Arc<Mutex<MockState>>;
+
+// 进程内的 mock Block Engine，同时提供 JSON-RPC (/bundles, /transactions) 和 gRPC SearcherService
+pub struct MockBlockEngine {
+    http_addr: SocketAddr,
+    grpc_addr: SocketAddr,
+    state: SharedState,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockBlockEngine {
+    pub async fn start() -> Result<Self> {
+        let state = Arc::new(Mutex::new(MockState {
+            started_at: Instant::now(),
+            start_slot: 1_000,
+            slot_duration: Duration::from_millis(400),
+            tip_accounts: crate::validation::MAINNET_TIP_ACCOUNTS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            regions: vec!["mock".to_string()],
+            leader_offset: 4,
+            leader_identity: "MockLeader1111111111111111111111111111111111".to_string(),
+            landing_delay: Duration::ZERO,
+            finalization_delay: Duration::ZERO,
+            outcome: MockBundleOutcome::Land,
+            batch_supported: true,
+            nonce_accounts: HashMap::new(),
+            simulation_failure: None,
+            scripted: VecDeque::new(),
+            requests: Vec::new(),
+            bundles: HashMap::new(),
+            subscribers: Vec::new(),
+        }));
+
+        let http_listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        http_listener.set_nonblocking(true)?;
+        let http_addr = http_listener.local_addr()?;
+        let http_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = http_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| handle_http(state.clone(), req)))
+            }
+        });
+        let http_server = hyper::Server::from_tcp(http_listener)?.serve(make_service);
+        let http_task = tokio::spawn(async move {
+            let _ = http_server.await;
+        });
+
+        let grpc_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let grpc_addr = grpc_listener.local_addr()?;
+        let service = MockSearcherService {
+            state: state.clone(),
+        };
+        let grpc_task = tokio::spawn(async move {
+            let _ = Server::builder()
+                .add_service(SearcherServiceServer::new(service))
+                .serve_with_incoming(TcpListenerStream::new(grpc_listener))
+                .await;
+        });
+
+        Ok(Self {
+            http_addr,
+            grpc_addr,
+            state,
+            tasks: vec![http_task, grpc_task],
+        })
+    }
+
+    // JitoJsonRpcSDK 使用的 base url
+    pub fn json_rpc_url(&self) -> String {
+        format!("http://{}/api/v1", self.http_addr)
+    }
+
+    // BlockhashManager 等使用的 Solana RPC 地址
+    pub fn solana_rpc_url(&self) -> String {
+        format!("http://{}/rpc", self.http_addr)
+    }
+
+    // GrpcClient 使用的地址
+    pub fn grpc_url(&self) -> String {
+        format!("http://{}", self.grpc_addr)
+    }
+
+    // 追加一个脚本化响应，作用于下一次请求
+    pub fn push_response(&self, response: MockResponse) {
+        self.state.lock().unwrap().scripted.push_back(response);
+    }
+
+    pub fn set_tip_accounts(&self, tip_accounts: Vec<String>) {
+        self.state.lock().unwrap().tip_accounts = tip_accounts;
+    }
+
+    pub fn set_regions(&self, regions: Vec<String>) {
+        self.state.lock().unwrap().regions = regions;
+    }
+
+    // 下一个 Jito leader 距当前 slot 的偏移
+    pub fn set_next_leader(&self, slots_ahead: u64, identity: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.leader_offset = slots_ahead;
+        state.leader_identity = identity.to_string();
+    }
+
+    pub fn set_slot_duration(&self, slot_duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.start_slot = state.current_slot();
+        state.started_at = Instant::now();
+        state.slot_duration = slot_duration;
+    }
+
+    // 之后提交的 bundle 在延迟后落地
+    pub fn set_landing_delay(&self, delay: Duration) {
+        self.state.lock().unwrap().landing_delay = delay;
+    }
+
+    // 落地后再经过该延迟变为 finalized
+    pub fn set_finalization_delay(&self, delay: Duration) {
+        self.state.lock().unwrap().finalization_delay = delay;
+    }
+
+    // 之后提交的 bundle 的结果
+    pub fn set_bundle_outcome(&self, outcome: MockBundleOutcome) {
+        self.state.lock().unwrap().outcome = outcome;
+    }
+
+    // 关闭后批量请求返回 -32600 错误，模拟不支持批量的服务端
+    pub fn set_batch_supported(&self, supported: bool) {
+        self.state.lock().unwrap().batch_supported = supported;
+    }
+
+    // 创建一个已初始化的 nonce 账户，nonce 由当前 slot 的 blockhash 生成，返回 nonce 值
+    pub fn set_nonce_account(&self, nonce_account: Pubkey, authority: Pubkey) -> Hash {
+        let mut state = self.state.lock().unwrap();
+        let blockhash = hashv(&[&state.current_slot().to_le_bytes()]);
+        let durable_nonce = DurableNonce::from_blockhash(&blockhash);
+        let data = bincode::serialize(&Versions::new(NonceState::new_initialized(
+            &authority,
+            durable_nonce,
+            5_000,
+        )))
+        .expect("serialize nonce account");
+        state.nonce_accounts.insert(nonce_account.to_string(), data);
+        *durable_nonce.as_hash()
+    }
+
+    // 之后的 simulateBundle 中第 index 笔交易执行失败，之后的交易不再执行；None 表示全部成功
+    pub fn set_simulation_failure(&self, index: Option<usize>) {
+        self.state.lock().unwrap().simulation_failure = index;
+    }
+
+    pub fn current_slot(&self) -> u64 {
+        self.state.lock().unwrap().current_slot()
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    // 忘记已提交的 bundle，模拟超出 Jito 状态查询窗口：之后 getInflightBundleStatuses 返回 Invalid，
+    // getBundleStatuses 返回 null
+    pub fn forget_bundles(&self) {
+        self.state.lock().unwrap().bundles.clear();
+    }
+
+    pub fn bundle_ids(&self) -> Vec<String> {
+        self.state.lock().unwrap().bundles.keys().cloned().collect()
+    }
+}
+
+impl Drop for MockBlockEngine {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+fn json_response(status: StatusCode, body: Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn rpc_error(id: &Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+}
+
+// 取出下一个脚本化响应，Delay 直接在这里等待
+async fn next_scripted(state: &SharedState) -> Option<MockResponse> {
+    let scripted = state.lock().unwrap().scripted.pop_front();
+    match scripted {
+        Some(MockResponse::Delay(delay)) => {
+            tokio::time::sleep(delay).await;
+            None
+        }
+        other => other,
+    }
+}
+
+async fn handle_http(state: SharedState, req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let path = req.uri().path().to_string();
+    let query = req.uri().query().map(String::from);
+    let body = hyper::body::to_bytes(req.into_body())
+        .await
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok())
+        .unwrap_or(Value::Null);
+    let id = body.get("id").cloned().unwrap_or(Value::Null);
+    let method = match &body {
+        Value::Array(_) => "batch".to_string(),
+        body => body
+            .get("method")
+            .and_then(|m| m.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    };
+
+    state.lock().unwrap().requests.push(RecordedRequest {
+        path: path.clone(),
+        query,
+        method,
+        body: body.clone(),
+    });
+
+    let mut mismatched_id = false;
+    match next_scripted(&state).await {
+        Some(MockResponse::MismatchedId) => mismatched_id = true,
+        Some(MockResponse::RateLimited) => {
+            return Ok(json_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                rpc_error(&id, -32097, "Rate limit exceeded. Limit: 1 per second for txn requests"),
+            ));
+        }
+        Some(MockResponse::Reject { code, message }) => {
+            return Ok(json_response(StatusCode::OK, rpc_error(&id, code, &message)));
+        }
+        _ => {}
+    }
+
+    if !path.ends_with("/bundles") && !path.ends_with("/transactions") && !path.ends_with("/rpc") {
+        return Ok(json_response(StatusCode::NOT_FOUND, rpc_error(&id, -32601, "Not found")));
+    }
+
+    // 批量请求按倒序返回，调用方需要按 id 对应
+    if let Value::Array(calls) = &body {
+        if !state.lock().unwrap().batch_supported {
+            return Ok(json_response(
+                StatusCode::OK,
+                rpc_error(&Value::Null, -32600, "Batch requests are not supported"),
+            ));
+        }
+        let responses = calls
+            .iter()
+            .rev()
+            .map(|call| handle_call(&state, &path, call))
+            .collect::<Vec<_>>();
+        return Ok(json_response(StatusCode::OK, Value::Array(responses)));
+    }
+
+    let mut response = handle_call(&state, &path, &body);
+    if mismatched_id {
+        response["id"] = json!(format!("mismatched-{}", id));
+    }
+    Ok(json_response(StatusCode::OK, response))
+}
+
+// 处理单个 JSON-RPC 调用
+fn handle_call(state: &SharedState, path: &str, call: &Value) -> Value {
+    let id = call.get("id").cloned().unwrap_or(Value::Null);
+    let method = call.get("method").and_then(|m| m.as_str()).unwrap_or_default();
+    let params = call.get("params").cloned().unwrap_or_else(|| json!([]));
+    let result = if path.ends_with("/bundles") {
+        handle_bundles(state, method, &params)
+    } else if path.ends_with("/rpc") {
+        handle_solana_rpc(state, method, &params)
+    } else {
+        handle_transactions(method, &params)
+    };
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => rpc_error(&id, code, &message),
+    }
+}
+
+type RpcResult = std::result::Result<Value, (i64, String)>;
+
+fn handle_bundles(state: &SharedState, method: &str, params: &Value) -> RpcResult {
+    let mut state = state.lock().unwrap();
+    match method {
+        "getTipAccounts" => Ok(json!(state.tip_accounts)),
+        "sendBundle" => {
+            let transactions = params[0]
+                .as_array()
+                .ok_or((-32602, "params[0] must be an array of transactions".to_string()))?
+                .iter()
+                .filter_map(|tx| tx.as_str().map(String::from))
+                .collect::<Vec<_>>();
+            if transactions.is_empty() || transactions.len() > 5 {
+                return Err((-32602, "bundle must contain 1 to 5 transactions".to_string()));
+            }
+            let encoding = params[1]["encoding"].as_str().unwrap_or("base58");
+            let signatures = transactions
+                .iter()
+                .map(|tx| decode_signature(tx, encoding).unwrap_or_else(|| tx.clone()))
+                .collect::<Vec<_>>();
+            let raw = transactions.iter().map(|tx| tx.as_bytes()).collect::<Vec<_>>();
+            Ok(json!(state.submit_bundle(signatures, &raw)))
+        }
+        "getBundleStatuses" => {
+            let slot = state.current_slot();
+            let value = bundle_ids_param(params)?
+                .iter()
+                .map(|id| {
+                    let bundle = state.bundles.get(id)?;
+                    match state.bundle_state(bundle) {
+                        BundleState::Landed { slot, finalized } => Some(json!({
+                            "bundle_id": id,
+                            "transactions": bundle.transactions,
+                            "slot": slot,
+                            "confirmation_status": if finalized { "finalized" } else { "confirmed" },
+                            "err": { "Ok": null },
+                        })),
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>();
+            Ok(json!({ "context": { "slot": slot }, "value": value }))
+        }
+        "getInflightBundleStatuses" => {
+            let slot = state.current_slot();
+            let value = bundle_ids_param(params)?
+                .iter()
+                .map(|id| {
+                    let (status, landed_slot) = match state.bundles.get(id).map(|b| state.bundle_state(b)) {
+                        None => ("Invalid", None),
+                        Some(BundleState::Pending) => ("Pending", None),
+                        Some(BundleState::Failed) => ("Failed", None),
+                        Some(BundleState::Landed { slot, .. }) => ("Landed", Some(slot)),
+                    };
+                    json!({ "bundle_id": id, "status": status, "landed_slot": landed_slot })
+                })
+                .collect::<Vec<_>>();
+            Ok(json!({ "context": { "slot": slot }, "value": value }))
+        }
+        _ => Err((-32601, format!("Method not found: {}", method))),
+    }
+}
+
+fn handle_transactions(method: &str, params: &Value) -> RpcResult {
+    match method {
+        "sendTransaction" => {
+            let tx = params[0]
+                .as_str()
+                .ok_or((-32602, "params[0] must be an encoded transaction".to_string()))?;
+            let encoding = params[1]["encoding"].as_str().unwrap_or("base58");
+            decode_signature(tx, encoding)
+                .map(Value::from)
+                .ok_or((-32602, "failed to deserialize transaction".to_string()))
+        }
+        _ => Err((-32601, format!("Method not found: {}", method))),
+    }
+}
+
+// Solana RPC 的子集，区块高度与 slot 相同（没有跳过的 slot），blockhash 由 slot 生成
+fn handle_solana_rpc(state: &SharedState, method: &str, params: &Value) -> RpcResult {
+    let state = state.lock().unwrap();
+    let slot = state.current_slot();
+    match method {
+        "getAccountInfo" => {
+            let value = params[0]
+                .as_str()
+                .and_then(|account| state.nonce_accounts.get(account))
+                .map(|data| {
+                    json!({
+                        "data": [general_purpose::STANDARD.encode(data), "base64"],
+                        "executable": false,
+                        "lamports": 1_447_680,
+                        "owner": system_program::id().to_string(),
+                        "rentEpoch": 0,
+                        "space": data.len(),
+                    })
+                });
+            Ok(json!({ "context": { "slot": slot }, "value": value }))
+        }
+        "getLatestBlockhash" => Ok(json!({
+            "context": { "slot": slot },
+            "value": {
+                "blockhash": hashv(&[&slot.to_le_bytes()]).to_string(),
+                "lastValidBlockHeight": slot + BLOCKHASH_VALID_SLOTS,
+            }
+        })),
+        "getBlockHeight" => Ok(json!(slot)),
+        "getSlot" => Ok(json!(slot)),
+        "simulateBundle" => simulate_bundle(slot, state.simulation_failure, params),
+        _ => Err((-32601, format!("Method not found: {}", method))),
+    }
+}
+
+// Jito-Solana simulateBundle：每条指令消耗 150 CU，失败的交易之后的交易不执行
+fn simulate_bundle(slot: u64, failure: Option<usize>, params: &Value) -> RpcResult {
+    let config = &params[1];
+    let encoding = config["transactionEncoding"]
+        .as_str()
+        .unwrap_or("base64")
+        .parse::<Encoding>()
+        .map_err(|e| (-32602, e.to_string()))?;
+    let transactions = params[0]["encodedTransactions"]
+        .as_array()
+        .ok_or((-32602, "params[0].encodedTransactions must be an array".to_string()))?
+        .iter()
+        .map(|tx| tx.as_str().and_then(|tx| encoding.decode_transaction(tx).ok()))
+        .collect::<Option<Vec<_>>>()
+        .ok_or((-32602, "failed to deserialize transaction".to_string()))?;
+
+    let mut accounts = Vec::new();
+    for key in ["preExecutionAccountsConfigs", "postExecutionAccountsConfigs"] {
+        let configs = config[key].as_array().cloned().unwrap_or_default();
+        if !configs.is_empty() && configs.len() != transactions.len() {
+            return Err((
+                -32602,
+                format!("{} must be equal in length to the number of transactions", key),
+            ));
+        }
+        accounts.push(configs);
+    }
+    let execution_accounts = |configs: &[Value], index: usize| match configs.get(index) {
+        Some(Value::Object(config)) => json!(config["addresses"]
+            .as_array()
+            .map(|addresses| addresses
+                .iter()
+                .map(|_| json!({
+                    "data": ["", "base64"],
+                    "executable": false,
+                    "lamports": 0,
+                    "owner": system_program::id().to_string(),
+                    "rentEpoch": 0,
+                }))
+                .collect::<Vec<_>>())
+            .unwrap_or_default()),
+        _ => Value::Null,
+    };
+
+    let mut summary = json!("succeeded");
+    let mut results = Vec::new();
+    for (index, tx) in transactions.iter().enumerate() {
+        let keys = tx.message.static_account_keys();
+        let programs = tx
+            .message
+            .instructions()
+            .iter()
+            .map(|ix| keys[ix.program_id_index as usize].to_string())
+            .collect::<Vec<_>>();
+        let failed = failure == Some(index);
+        let executed = if failed { &programs[..programs.len().min(1)] } else { &programs[..] };
+        let logs = executed
+            .iter()
+            .flat_map(|program| {
+                let result = match failed {
+                    true => format!("Program {} failed: custom program error: 0x1", program),
+                    false => format!("Program {} success", program),
+                };
+                [format!("Program {} invoke [1]", program), result]
+            })
+            .collect::<Vec<_>>();
+        let error = json!({ "InstructionError": [0, { "Custom": 1 }] });
+        results.push(json!({
+            "err": if failed { error.clone() } else { Value::Null },
+            "logs": logs,
+            "preExecutionAccounts": execution_accounts(&accounts[0], index),
+            "postExecutionAccounts": if failed { Value::Null } else { execution_accounts(&accounts[1], index) },
+            "unitsConsumed": 150 * executed.len() as u64,
+            "returnData": null,
+        }));
+        if failed {
+            summary = json!({
+                "failed": {
+                    "error": { "TransactionFailure": [error, "Error processing Instruction 0: custom program error: 0x1"] },
+                    "tx_signature": tx.signatures[0].to_string(),
+                }
+            });
+            break;
+        }
+    }
+    Ok(json!({
+        "context": { "slot": slot },
+        "value": { "summary": summary, "transactionResults": results }
+    }))
+}
+
+fn bundle_ids_param(params: &Value) -> std::result::Result<Vec<String>, (i64, String)> {
+    params[0]
+        .as_array()
+        .map(|ids| ids.iter().filter_map(|id| id.as_str().map(String::from)).collect())
+        .ok_or((-32602, "params[0] must be an array of bundle ids".to_string()))
+}
+
+// 解析编码后的交易，返回第一个签名；未指定编码时按 Jito 默认的 base58 处理
+fn decode_signature(tx: &str, encoding: &str) -> Option<String> {
+    let encoding = encoding.parse::<Encoding>().ok()?;
+    let tx = encoding.decode_transaction(tx).ok()?;
+    tx.signatures.first().map(|s| s.to_string())
+}
+
+struct MockSearcherService {
+    state: SharedState,
+}
+
+impl MockSearcherService {
+    async fn record(&self, method: &str, body: Value) -> Result<(), Status> {
+        self.state.lock().unwrap().requests.push(RecordedRequest {
+            path: format!("/searcher.SearcherService/{}", method),
+            query: None,
+            method: method.to_string(),
+            body,
+        });
+        match next_scripted(&self.state).await {
+            Some(MockResponse::RateLimited) => Err(Status::resource_exhausted("Rate limit exceeded")),
+            Some(MockResponse::Reject { message, .. }) => Err(Status::invalid_argument(message)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl SearcherService for MockSearcherService {
+    type SubscribeBundleResultsStream = ReceiverStream<Result<BundleResult, Status>>;
+
+    async fn subscribe_bundle_results(
+        &self,
+        _request: tonic::Request<SubscribeBundleResultsRequest>,
+    ) -> Result<tonic::Response<Self::SubscribeBundleResultsStream>, Status> {
+        self.record("SubscribeBundleResults", Value::Null).await?;
+        let (tx, rx) = mpsc::channel(64);
+        self.state.lock().unwrap().subscribers.push(tx);
+        Ok(tonic::Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn send_bundle(
+        &self,
+        request: tonic::Request<SendBundleRequest>,
+    ) -> Result<tonic::Response<SendBundleResponse>, Status> {
+        let packets = request
+            .into_inner()
+            .bundle
+            .map(|bundle| bundle.packets)
+            .unwrap_or_default();
+        self.record("SendBundle", json!({ "packets": packets.len() })).await?;
+        if packets.is_empty() || packets.len() > 5 {
+            return Err(Status::invalid_argument("bundle must contain 1 to 5 transactions"));
+        }
+
+        let signatures = packets
+            .iter()
+            .map(|packet| {
+                bincode::deserialize::<VersionedTransaction>(&packet.data)
+                    .ok()
+                    .and_then(|tx| tx.signatures.first().map(|s| s.to_string()))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let raw = packets.iter().map(|p| p.data.as_slice()).collect::<Vec<_>>();
+        let uuid = self.state.lock().unwrap().submit_bundle(signatures, &raw);
+        Ok(tonic::Response::new(SendBundleResponse { uuid }))
+    }
+
+    async fn get_next_scheduled_leader(
+        &self,
+        _request: tonic::Request<NextScheduledLeaderRequest>,
+    ) -> Result<tonic::Response<NextScheduledLeaderResponse>, Status> {
+        self.record("GetNextScheduledLeader", Value::Null).await?;
+        let state = self.state.lock().unwrap();
+        let current_slot = state.current_slot();
+        Ok(tonic::Response::new(NextScheduledLeaderResponse {
+            current_slot,
+            next_leader_slot: current_slot + state.leader_offset,
+            next_leader_identity: state.leader_identity.clone(),
+            next_leader_region: state.regions.first().cloned().unwrap_or_default(),
+        }))
+    }
+
+    async fn get_connected_leaders(
+        &self,
+        _request: tonic::Request<ConnectedLeadersRequest>,
+    ) -> Result<tonic::Response<ConnectedLeadersResponse>, Status> {
+        self.record("GetConnectedLeaders", Value::Null).await?;
+        Ok(tonic::Response::new(ConnectedLeadersResponse::default()))
+    }
+
+    async fn get_connected_leaders_regioned(
+        &self,
+        _request: tonic::Request<ConnectedLeadersRegionedRequest>,
+    ) -> Result<tonic::Response<ConnectedLeadersRegionedResponse>, Status> {
+        self.record("GetConnectedLeadersRegioned", Value::Null).await?;
+        Ok(tonic::Response::new(ConnectedLeadersRegionedResponse::default()))
+    }
+
+    async fn get_tip_accounts(
+        &self,
+        _request: tonic::Request<GetTipAccountsRequest>,
+    ) -> Result<tonic::Response<GetTipAccountsResponse>, Status> {
+        self.record("GetTipAccounts", Value::Null).await?;
+        let accounts = self.state.lock().unwrap().tip_accounts.clone();
+        Ok(tonic::Response::new(GetTipAccountsResponse { accounts }))
+    }
+
+    async fn get_regions(
+        &self,
+        _request: tonic::Request<GetRegionsRequest>,
+    ) -> Result<tonic::Response<GetRegionsResponse>, Status> {
+        self.record("GetRegions", Value::Null).await?;
+        let regions = self.state.lock().unwrap().regions.clone();
+        Ok(tonic::Response::new(GetRegionsResponse {
+            current_region: regions.first().cloned().unwrap_or_default(),
+            available_regions: regions,
+        }))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::blockhash::rpc_call;
+use crate::transport::{JsonRpcTransport, ReqwestTransport};
+use crate::versioned::unsigned_v0_transaction;
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::json;
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    nonce::state::{State, Versions},
+    pubkey::Pubkey,
+    system_instruction::{self, SystemInstruction},
+    system_program,
+    transaction::VersionedTransaction,
+};
+use std::sync::Arc;
+
+// 已初始化的 nonce 账户状态，nonce 作为交易的 recent blockhash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonceInfo {
+    pub nonce_account: Pubkey,
+    pub authority: Pubkey,
+    pub nonce: Hash,
+    pub lamports_per_signature: u64,
+}
+
+impl NonceInfo {
+    // 必须作为交易的第一条指令
+    pub fn advance_instruction(&self) -> Instruction {
+        system_instruction::advance_nonce_account(&self.nonce_account, &self.authority)
+    }
+}
+
+// 解析 nonce 账户数据
+pub fn parse_nonce_account(nonce_account: &Pubkey, data: &[u8]) -> Result<NonceInfo> {
+    let versions = bincode::deserialize::<Versions>(data)
+        .map_err(|e| anyhow!("Invalid nonce account {}: {}", nonce_account, e))?;
+    match versions.state() {
+        State::Initialized(data) => Ok(NonceInfo {
+            nonce_account: *nonce_account,
+            authority: data.authority,
+            nonce: data.blockhash(),
+            lamports_per_signature: data.get_lamports_per_signature(),
+        }),
+        State::Uninitialized => Err(anyhow!(
+            "Nonce account {} is not initialized",
+            nonce_account
+        )),
+    }
+}
+
+// 通过 Solana RPC 读取 nonce 账户
+pub struct NonceClient {
+    rpc_url: String,
+    transport: Arc<dyn JsonRpcTransport>,
+}
+
+impl NonceClient {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_url: rpc_url.to_string(),
+            transport: Arc::new(ReqwestTransport::default()),
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn JsonRpcTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub async fn get_nonce(&self, nonce_account: &Pubkey) -> Result<NonceInfo> {
+        let result = rpc_call(
+            &self.rpc_url,
+            self.transport.as_ref(),
+            "getAccountInfo",
+            json!([
+                nonce_account.to_string(),
+                { "encoding": "base64", "commitment": "confirmed" }
+            ]),
+        )
+        .await?;
+        let account = &result["value"];
+        if account.is_null() {
+            return Err(anyhow!("Nonce account {} not found", nonce_account));
+        }
+        if account["owner"].as_str() != Some(&system_program::id().to_string()) {
+            return Err(anyhow!(
+                "Account {} is not a nonce account (owner {})",
+                nonce_account,
+                account["owner"]
+            ));
+        }
+        let data = account["data"][0]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing data for nonce account {}", nonce_account))?;
+        let data = general_purpose::STANDARD.decode(data).map_err(|e| {
+            anyhow!(
+                "Invalid base64 data for nonce account {}: {}",
+                nonce_account,
+                e
+            )
+        })?;
+        parse_nonce_account(nonce_account, &data)
+    }
+}
+
+// 构造使用 durable nonce 的未签名 v0 交易：第一条指令为 advance nonce，recent blockhash 为 nonce 值。
+// nonce authority 需要签名
+pub fn nonced_v0_transaction(
+    payer: &Pubkey,
+    nonce: &NonceInfo,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<VersionedTransaction> {
+    let mut all = Vec::with_capacity(instructions.len() + 1);
+    all.push(nonce.advance_instruction());
+    all.extend_from_slice(instructions);
+    unsigned_v0_transaction(payer, &all, lookup_tables, nonce.nonce)
+}
+
+// 交易中第一条 advance nonce 指令的位置
+pub fn advance_nonce_index(tx: &VersionedTransaction) -> Option<usize> {
+    let keys = tx.message.static_account_keys();
+    tx.message.instructions().iter().position(|ix| {
+        keys.get(ix.program_id_index as usize) == Some(&system_program::id())
+            && matches!(
+                bincode::deserialize::<SystemInstruction>(&ix.data),
+                Ok(SystemInstruction::AdvanceNonceAccount)
+            )
+    })
+}
+
+// 交易是否使用 durable nonce（advance nonce 为第一条指令）
+pub fn uses_durable_nonce(tx: &VersionedTransaction) -> bool {
+    advance_nonce_index(tx) == Some(0)
+}
@@ -0,0 +1,226 @@
+use crate::blockhash::BlockhashManager;
+use crate::tracker::{BundleState, BundleTracker, BundleTransition};
+use crate::JitoJsonRpcSDK;
+use anyhow::{anyhow, Result};
+use solana_sdk::transaction::VersionedTransaction;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// 一个 slot 大约 400ms
+pub const SLOT_DURATION: Duration = Duration::from_millis(400);
+// 每轮向每个区域发送一次，并在间隔中点查询一次状态；Jito 默认限制每个 IP 每秒 1 个请求
+pub const DEFAULT_RESUBMIT_INTERVAL: Duration = Duration::from_secs(2);
+pub const DEFAULT_SUBMIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+// submit_until_landed 的参数
+pub struct SubmitOptions {
+    interval: Duration,
+    timeout: Duration,
+    regions: Vec<JitoJsonRpcSDK>,
+    expiry: Option<(Arc<BlockhashManager>, u64)>,
+    tip_lamports: u64,
+    stop_on_failure: bool,
+}
+
+impl Default for SubmitOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_RESUBMIT_INTERVAL,
+            timeout: DEFAULT_SUBMIT_TIMEOUT,
+            regions: Vec::new(),
+            expiry: None,
+            tip_lamports: 0,
+            stop_on_failure: false,
+        }
+    }
+}
+
+impl SubmitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 两次发送之间的间隔，期间查询一次 bundle 状态
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_interval_slots(self, slots: u64) -> Self {
+        let slots = u32::try_from(slots).unwrap_or(u32::MAX);
+        self.with_interval(SLOT_DURATION.saturating_mul(slots))
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // 每轮同时发往其他区域的 block engine，状态仍通过调用方的 SDK 查询
+    pub fn with_region(mut self, sdk: JitoJsonRpcSDK) -> Self {
+        self.regions.push(sdk);
+        self
+    }
+
+    // 区块高度超过 last_valid_block_height 后停止发送
+    pub fn with_expiry(
+        mut self,
+        blockhash: Arc<BlockhashManager>,
+        last_valid_block_height: u64,
+    ) -> Self {
+        self.expiry = Some((blockhash, last_valid_block_height));
+        self
+    }
+
+    // 落地时计入 tip 花费指标
+    pub fn with_tip_lamports(mut self, tip_lamports: u64) -> Self {
+        self.tip_lamports = tip_lamports;
+        self
+    }
+
+    // Jito 在 bundle 没有赢得拍卖或未被打包时也报告 Failed，默认继续重发；
+    // 设为 true 时在第一次 Failed 时停止并返回 SubmitOutcome::Failed
+    pub fn with_stop_on_failure(mut self, stop_on_failure: bool) -> Self {
+        self.stop_on_failure = stop_on_failure;
+        self
+    }
+
+    fn is_expired(&self) -> bool {
+        matches!(&self.expiry, Some((blockhash, height)) if !blockhash.is_valid(*height))
+    }
+}
+
+// 一次发送
+#[derive(Debug)]
+pub struct SubmitAttempt {
+    pub round: u32,
+    pub region: String, // block engine 的 base url
+    pub elapsed: Duration,
+    pub result: Result<String>, // bundle id
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Landed {
+        bundle_id: String,
+        slot: Option<u64>,
+    },
+    Failed {
+        bundle_id: String,
+    },
+    Expired,
+    TimedOut,
+}
+
+#[derive(Debug)]
+pub struct SubmitReport {
+    pub outcome: SubmitOutcome,
+    pub attempts: Vec<SubmitAttempt>,
+    pub transitions: Vec<BundleTransition>,
+    pub elapsed: Duration,
+}
+
+impl SubmitReport {
+    pub fn is_landed(&self) -> bool {
+        matches!(self.outcome, SubmitOutcome::Landed { .. })
+    }
+}
+
+impl JitoJsonRpcSDK {
+    // 每隔 interval 重新发送同一个 bundle（Jito 只在当前拍卖中考虑 bundle），
+    // 直到落地、blockhash 过期或超时（可选在失败时停止）；发送失败只记录在 attempts 中，不会中断
+    pub async fn submit_until_landed(
+        &self,
+        transactions: &[VersionedTransaction],
+        options: &SubmitOptions,
+    ) -> Result<SubmitReport> {
+        if transactions.is_empty() {
+            return Err(anyhow!("Bundle must contain at least one transaction"));
+        }
+
+        let started = Instant::now();
+        let mut tracker = BundleTracker::new();
+        tracker.metrics = self.metrics.clone();
+        let mut attempts = Vec::new();
+        let mut transitions = Vec::new();
+        let mut round = 0;
+
+        let outcome = loop {
+            if let Some(height) = options.expiry.as_ref().and_then(|(b, _)| b.block_height()) {
+                transitions.extend(tracker.expire(height));
+            }
+            if options.is_expired() {
+                break SubmitOutcome::Expired;
+            }
+            if started.elapsed() >= options.timeout {
+                break SubmitOutcome::TimedOut;
+            }
+
+            for sdk in std::iter::once(self).chain(&options.regions) {
+                let result = sdk
+                    .send_bundle_transactions(transactions, sdk.uuid.as_deref())
+                    .await
+                    .and_then(|response| {
+                        response["result"]
+                            .as_str()
+                            .map(String::from)
+                            .ok_or_else(|| anyhow!("Missing bundle id in sendBundle response"))
+                    });
+                if let Ok(bundle_id) = &result {
+                    match &options.expiry {
+                        Some((_, height)) => {
+                            tracker.track_until(bundle_id, options.tip_lamports, *height)
+                        }
+                        None => tracker.track(bundle_id, options.tip_lamports),
+                    }
+                }
+                attempts.push(SubmitAttempt {
+                    round,
+                    region: sdk.base_url.clone(),
+                    elapsed: started.elapsed(),
+                    result,
+                });
+            }
+            round += 1;
+
+            // 状态查询放在间隔中点，与下一轮发送错开
+            let half = options.interval / 2;
+            let remaining = options.timeout.saturating_sub(started.elapsed());
+            tokio::time::sleep(half.min(remaining)).await;
+
+            if !tracker.bundles().is_empty() {
+                match tracker.poll(self).await {
+                    Ok(polled) => transitions.extend(polled),
+                    Err(e) => tracing::warn!("Failed to poll bundle statuses: {}", e),
+                }
+            }
+            if let Some(bundle) = tracker.bundles().iter().find(|b| b.state.is_landed()) {
+                break SubmitOutcome::Landed {
+                    bundle_id: bundle.bundle_id.clone(),
+                    slot: bundle.slot,
+                };
+            }
+            if options.stop_on_failure {
+                let failed = tracker
+                    .bundles()
+                    .iter()
+                    .find(|b| b.state == BundleState::Failed);
+                if let Some(bundle) = failed {
+                    break SubmitOutcome::Failed {
+                        bundle_id: bundle.bundle_id.clone(),
+                    };
+                }
+            }
+
+            let remaining = options.timeout.saturating_sub(started.elapsed());
+            tokio::time::sleep((options.interval - half).min(remaining)).await;
+        };
+
+        Ok(SubmitReport {
+            outcome,
+            attempts,
+            transitions,
+            elapsed: started.elapsed(),
+        })
+    }
+}
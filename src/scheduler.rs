@@ -0,0 +1,185 @@
+use crate::grpc::GrpcClient;
+use crate::proto::searcher::NextScheduledLeaderResponse;
+use anyhow::Result;
+use solana_sdk::transaction::VersionedTransaction;
+use std::collections::HashMap;
+
+// 默认在 Jito leader 之前 2 个 slot 发送
+pub const DEFAULT_LEAD_SLOTS: u64 = 2;
+// blockhash 大约在 150 个 slot 后过期，可用于从获取 blockhash 时的 slot 推算截止 slot
+pub const BLOCKHASH_VALID_SLOTS: u64 = 150;
+
+// 当前 slot 和下一个 Jito leader
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderWindow {
+    pub current_slot: u64,
+    pub next_leader_slot: u64,
+    pub next_leader_identity: String,
+    pub region: String,
+}
+
+impl LeaderWindow {
+    // 距离下一个 Jito leader 的 slot 数，leader 正在出块时为 0
+    pub fn slots_until_leader(&self) -> u64 {
+        self.next_leader_slot.saturating_sub(self.current_slot)
+    }
+}
+
+impl From<NextScheduledLeaderResponse> for LeaderWindow {
+    fn from(response: NextScheduledLeaderResponse) -> Self {
+        Self {
+            current_slot: response.current_slot,
+            next_leader_slot: response.next_leader_slot,
+            next_leader_identity: response.next_leader_identity,
+            region: response.next_leader_region,
+        }
+    }
+}
+
+// 等待发送的 bundle，deadline_slot 之后 blockhash 失效
+#[derive(Debug, Clone)]
+pub struct ScheduledBundle {
+    pub id: u64,
+    pub transactions: Vec<VersionedTransaction>,
+    pub deadline_slot: u64,
+}
+
+#[derive(Debug)]
+pub enum ScheduleEvent {
+    Sent {
+        id: u64,
+        region: String,
+        leader_slot: u64,
+        bundle_id: String,
+    },
+    SendFailed {
+        id: u64,
+        region: String,
+        error: String,
+    },
+    // 下一个 Jito leader 之前 blockhash 就会过期
+    Dropped {
+        id: u64,
+        deadline_slot: u64,
+        leader_slot: u64,
+    },
+}
+
+// 按 Jito leader 排期发送 bundle：在 leader slot 之前 lead_slots 个 slot 发往 leader 所在区域的 block engine，
+// 期间 blockhash 会过期的 bundle 直接丢弃，避免在非 Jito leader 期间浪费请求额度
+pub struct BundleScheduler {
+    leader_client: GrpcClient,
+    engines: HashMap<String, GrpcClient>,
+    lead_slots: u64,
+    window: Option<LeaderWindow>,
+    queue: Vec<ScheduledBundle>,
+    next_id: u64,
+}
+
+impl BundleScheduler {
+    // leader_client 用于查询 leader 排期，未配置区域的 bundle 也通过它发送
+    pub fn new(leader_client: GrpcClient) -> Self {
+        Self {
+            leader_client,
+            engines: HashMap::new(),
+            lead_slots: DEFAULT_LEAD_SLOTS,
+            window: None,
+            queue: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    // 区域名与 GetRegions 返回的一致，例如 "ny"、"amsterdam"
+    pub fn with_region(mut self, region: &str, client: GrpcClient) -> Self {
+        self.engines.insert(region.to_string(), client);
+        self
+    }
+
+    pub fn with_lead_slots(mut self, lead_slots: u64) -> Self {
+        self.lead_slots = lead_slots;
+        self
+    }
+
+    // 加入队列，返回用于匹配 ScheduleEvent 的 id
+    pub fn schedule(&mut self, transactions: Vec<VersionedTransaction>, deadline_slot: u64) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.queue.push(ScheduledBundle {
+            id,
+            transactions,
+            deadline_slot,
+        });
+        id
+    }
+
+    pub fn pending(&self) -> &[ScheduledBundle] {
+        &self.queue
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    // 最近一次查询到的 leader 排期
+    pub fn window(&self) -> Option<&LeaderWindow> {
+        self.window.as_ref()
+    }
+
+    pub async fn refresh(&mut self) -> Result<&LeaderWindow> {
+        let mut regions = self.engines.keys().cloned().collect::<Vec<_>>();
+        regions.sort();
+        let response = self
+            .leader_client
+            .get_next_scheduled_leader(regions)
+            .await?;
+        Ok(self.window.insert(response.into()))
+    }
+
+    // 刷新 leader 排期并发送到期的 bundle，应以不超过一个 slot（约 400ms）的间隔调用
+    pub async fn tick(&mut self) -> Result<Vec<ScheduleEvent>> {
+        let window = self.refresh().await?.clone();
+        let (release, mut events) = self.plan(&window);
+
+        let client = self
+            .engines
+            .get(&window.region)
+            .unwrap_or(&self.leader_client);
+        for bundle in release {
+            match client.send_bundle(bundle.transactions).await {
+                Ok(bundle_id) => events.push(ScheduleEvent::Sent {
+                    id: bundle.id,
+                    region: window.region.clone(),
+                    leader_slot: window.next_leader_slot,
+                    bundle_id,
+                }),
+                Err(e) => events.push(ScheduleEvent::SendFailed {
+                    id: bundle.id,
+                    region: window.region.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+        Ok(events)
+    }
+
+    // 从队列中取出本轮要发送的 bundle，并丢弃来不及落地的 bundle
+    fn plan(&mut self, window: &LeaderWindow) -> (Vec<ScheduledBundle>, Vec<ScheduleEvent>) {
+        let due = window.slots_until_leader() <= self.lead_slots;
+        let mut release = Vec::new();
+        let mut dropped = Vec::new();
+        for bundle in std::mem::take(&mut self.queue) {
+            if bundle.deadline_slot < window.next_leader_slot {
+                dropped.push(ScheduleEvent::Dropped {
+                    id: bundle.id,
+                    deadline_slot: bundle.deadline_slot,
+                    leader_slot: window.next_leader_slot,
+                });
+            } else if due {
+                release.push(bundle);
+            } else {
+                self.queue.push(bundle);
+            }
+        }
+        (release, dropped)
+    }
+}
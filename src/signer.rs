@@ -0,0 +1,229 @@
+use crate::transport::{JsonRpcTransport, ReqwestTransport};
+use crate::{check_response_id, next_request_id};
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde_json::{json, Value};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::VersionedTransaction,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub type SignFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Vec<(Pubkey, Signature)>>> + Send + 'a>>;
+
+// bundle 签名接口，本地 Keypair 和远程签名服务都可实现。
+// sign 只需返回自己持有的、且在 pubkeys 中的签名，缺少的签名由 sign_bundle 报错
+pub trait BundleSigner: Send + Sync {
+    fn pubkeys(&self) -> Vec<Pubkey>;
+    fn sign<'a>(&'a self, message: &'a [u8], pubkeys: &'a [Pubkey]) -> SignFuture<'a>;
+}
+
+// 本地签名是同步的，签好后包装成 future
+fn sign_locally<'a>(signers: &[&dyn Signer], message: &[u8], pubkeys: &[Pubkey]) -> SignFuture<'a> {
+    let signatures = signers
+        .iter()
+        .filter(|signer| pubkeys.contains(&signer.pubkey()))
+        .map(|signer| {
+            signer
+                .try_sign_message(message)
+                .map(|signature| (signer.pubkey(), signature))
+                .map_err(|e| anyhow!("Failed to sign with {}: {}", signer.pubkey(), e))
+        })
+        .collect::<Result<Vec<_>>>();
+    Box::pin(async move { signatures })
+}
+
+impl BundleSigner for Keypair {
+    fn pubkeys(&self) -> Vec<Pubkey> {
+        vec![self.pubkey()]
+    }
+
+    fn sign<'a>(&'a self, message: &'a [u8], pubkeys: &'a [Pubkey]) -> SignFuture<'a> {
+        sign_locally(&[self as &dyn Signer], message, pubkeys)
+    }
+}
+
+// 多个本地签名者。BundleSigner 要求 Send + Sync（签名 future 可能跨线程执行），
+// 因此实现在 Vec<Box<dyn Signer + Send + Sync>> 上，而不是 Vec<Box<dyn Signer>>
+impl BundleSigner for Vec<Box<dyn Signer + Send + Sync>> {
+    fn pubkeys(&self) -> Vec<Pubkey> {
+        self.iter().map(|signer| signer.pubkey()).collect()
+    }
+
+    fn sign<'a>(&'a self, message: &'a [u8], pubkeys: &'a [Pubkey]) -> SignFuture<'a> {
+        let signers = self
+            .iter()
+            .map(|signer| signer.as_ref() as &dyn Signer)
+            .collect::<Vec<_>>();
+        sign_locally(&signers, message, pubkeys)
+    }
+}
+
+// 组合本地和远程签名者，按顺序收集签名
+impl BundleSigner for Vec<Box<dyn BundleSigner>> {
+    fn pubkeys(&self) -> Vec<Pubkey> {
+        self.iter().flat_map(|signer| signer.pubkeys()).collect()
+    }
+
+    fn sign<'a>(&'a self, message: &'a [u8], pubkeys: &'a [Pubkey]) -> SignFuture<'a> {
+        Box::pin(async move {
+            let mut signatures = Vec::new();
+            for signer in self {
+                let held = signer
+                    .pubkeys()
+                    .into_iter()
+                    .filter(|pubkey| pubkeys.contains(pubkey))
+                    .collect::<Vec<_>>();
+                if !held.is_empty() {
+                    signatures.extend(signer.sign(message, &held).await?);
+                }
+            }
+            Ok(signatures)
+        })
+    }
+}
+
+// 远程签名服务，通过 JSON-RPC signMessage 签名：
+// params 为 [{"message": <base64 消息>, "pubkeys": [<base58 公钥>]}]，
+// result 为 [{"pubkey": <base58 公钥>, "signature": <base58 签名>}]
+pub struct RemoteSigner {
+    url: String,
+    pubkeys: Vec<Pubkey>,
+    transport: Arc<dyn JsonRpcTransport>,
+}
+
+impl RemoteSigner {
+    // pubkeys 为远程服务持有私钥的账户
+    pub fn new(url: &str, pubkeys: Vec<Pubkey>) -> Self {
+        Self {
+            url: url.to_string(),
+            pubkeys,
+            transport: Arc::new(ReqwestTransport::default()),
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn JsonRpcTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    async fn request(
+        &self,
+        message: &[u8],
+        pubkeys: &[Pubkey],
+    ) -> Result<Vec<(Pubkey, Signature)>> {
+        let id = next_request_id();
+        let data = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "signMessage",
+            "params": [{
+                "message": STANDARD.encode(message),
+                "pubkeys": pubkeys.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+            }],
+        });
+        let response = self.transport.post(&self.url, &data).await?;
+        let body = response
+            .body
+            .ok_or_else(|| anyhow!("Invalid response (HTTP {})", response.status))?;
+        check_response_id(&body, id)?;
+        if let Some(error) = body.get("error") {
+            return Err(anyhow!("{}", error));
+        }
+        body["result"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Missing result in signMessage response"))?
+            .iter()
+            .map(parse_signature)
+            .collect()
+    }
+}
+
+fn parse_signature(value: &Value) -> Result<(Pubkey, Signature)> {
+    let pubkey = value["pubkey"]
+        .as_str()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| anyhow!("Invalid pubkey in signMessage response: {}", value))?;
+    let signature = value["signature"]
+        .as_str()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| anyhow!("Invalid signature in signMessage response: {}", value))?;
+    Ok((pubkey, signature))
+}
+
+impl BundleSigner for RemoteSigner {
+    fn pubkeys(&self) -> Vec<Pubkey> {
+        self.pubkeys.clone()
+    }
+
+    fn sign<'a>(&'a self, message: &'a [u8], pubkeys: &'a [Pubkey]) -> SignFuture<'a> {
+        Box::pin(async move {
+            self.request(message, pubkeys)
+                .await
+                .map_err(|e| anyhow!("Remote signer {} failed: {}", self.url, e))
+        })
+    }
+}
+
+// 为 bundle 中每笔交易补齐签名：只向签名者请求该交易需要的账户，
+// 校验返回的签名，仍有缺失时报错并列出缺少签名的账户
+pub async fn sign_bundle<S: BundleSigner + ?Sized>(
+    transactions: &mut [VersionedTransaction],
+    signer: &S,
+) -> Result<()> {
+    let available = signer.pubkeys();
+    for (index, tx) in transactions.iter_mut().enumerate() {
+        let message = tx.message.serialize();
+        let required = tx.message.header().num_required_signatures as usize;
+        let required_keys = tx
+            .message
+            .static_account_keys()
+            .get(..required)
+            .ok_or_else(|| anyhow!("Transaction {} has fewer accounts than signers", index))?
+            .to_vec();
+        tx.signatures.resize(required, Signature::default());
+
+        let wanted = required_keys
+            .iter()
+            .zip(&tx.signatures)
+            .filter(|(pubkey, signature)| {
+                **signature == Signature::default() && available.contains(pubkey)
+            })
+            .map(|(pubkey, _)| *pubkey)
+            .collect::<Vec<_>>();
+        if !wanted.is_empty() {
+            for (pubkey, signature) in signer.sign(&message, &wanted).await? {
+                let Some(position) = required_keys.iter().position(|key| *key == pubkey) else {
+                    continue;
+                };
+                if !signature.verify(pubkey.as_ref(), &message) {
+                    return Err(anyhow!(
+                        "Invalid signature from {} for transaction {}",
+                        pubkey,
+                        index
+                    ));
+                }
+                tx.signatures[position] = signature;
+            }
+        }
+
+        let missing = required_keys
+            .iter()
+            .zip(&tx.signatures)
+            .filter(|(_, signature)| **signature == Signature::default())
+            .map(|(pubkey, _)| pubkey.to_string())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(anyhow!(
+                "Transaction {} is missing signatures for: {}",
+                index,
+                missing.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use crate::encoding::Encoding;
+use crate::metrics::{self, Metrics, MetricsSink};
+use crate::transport::{JsonRpcTransport, ReqwestTransport};
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
+use std::sync::Arc;
+use std::time::Instant;
+
+// simulateBundle 的参数
+#[derive(Debug, Clone, Default)]
+pub struct SimulateBundleConfig {
+    // 每笔交易执行前/后需要返回的账户，None 表示不返回
+    pub pre_execution_accounts: Vec<Option<Vec<Pubkey>>>,
+    pub post_execution_accounts: Vec<Option<Vec<Pubkey>>>,
+    pub skip_sig_verify: bool,
+    pub replace_recent_blockhash: bool,
+}
+
+// 单笔交易的模拟结果
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedTransaction {
+    pub err: Option<Value>,
+    pub logs: Option<Vec<String>>,
+    pub units_consumed: Option<u64>,
+    pub pre_execution_accounts: Option<Vec<Value>>,
+    pub post_execution_accounts: Option<Vec<Value>>,
+    pub return_data: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimulationSummary {
+    Succeeded,
+    Failed {
+        error: Value,
+        tx_signature: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulateBundleResult {
+    pub slot: u64,
+    pub summary: SimulationSummary,
+    pub transaction_results: Vec<SimulatedTransaction>,
+}
+
+impl SimulateBundleResult {
+    pub fn is_success(&self) -> bool {
+        self.summary == SimulationSummary::Succeeded
+    }
+
+    pub fn units_consumed(&self) -> u64 {
+        self.transaction_results
+            .iter()
+            .filter_map(|tx| tx.units_consumed)
+            .sum()
+    }
+
+    // 失败原因，成功时为 None
+    pub fn failure_reason(&self) -> Option<String> {
+        match &self.summary {
+            SimulationSummary::Succeeded => None,
+            SimulationSummary::Failed {
+                error,
+                tx_signature,
+            } => Some(match tx_signature {
+                Some(signature) => format!("Transaction {} failed: {}", signature, error),
+                None => format!("Bundle failed: {}", error),
+            }),
+        }
+    }
+}
+
+// 调用 Jito-Solana RPC 的 simulateBundle
+pub struct BundleSimulator {
+    rpc_url: String,
+    transport: Arc<dyn JsonRpcTransport>,
+    metrics: Metrics,
+}
+
+impl BundleSimulator {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_url: rpc_url.to_string(),
+            transport: Arc::new(ReqwestTransport::default()),
+            metrics: Metrics::default(),
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn JsonRpcTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    // 与 JitoJsonRpcSDK 相同，记录每次 simulateBundle 调用的耗时和结果分类
+    pub fn with_metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Metrics::new(sink);
+        self
+    }
+
+    pub async fn simulate_bundle(
+        &self,
+        transactions: &[VersionedTransaction],
+        config: &SimulateBundleConfig,
+    ) -> Result<SimulateBundleResult> {
+        let encoded_transactions = transactions
+            .iter()
+            .map(|tx| Encoding::Base64.encode_transaction(tx))
+            .collect::<Result<Vec<_>>>()?;
+
+        let params = json!([
+            { "encodedTransactions": encoded_transactions },
+            {
+                "skipSigVerify": config.skip_sig_verify,
+                "replaceRecentBlockhash": config.replace_recent_blockhash,
+                "preExecutionAccountsConfigs": accounts_configs(&config.pre_execution_accounts, transactions.len())?,
+                "postExecutionAccountsConfigs": accounts_configs(&config.post_execution_accounts, transactions.len())?,
+                "transactionEncoding": Encoding::Base64.as_str(),
+            }
+        ]);
+
+        let id = crate::next_request_id();
+        let data = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "simulateBundle",
+            "params": params
+        });
+
+        let started = Instant::now();
+        let response = match self.transport.post(&self.rpc_url, &data).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.metrics.record_request(
+                    "json_rpc",
+                    "simulateBundle",
+                    metrics::OUTCOME_TRANSPORT_ERROR,
+                    started.elapsed(),
+                );
+                return Err(anyhow!("Request error: {}", e));
+            }
+        };
+        let id_check = response
+            .body
+            .as_ref()
+            .map(|body| crate::check_response_id(body, id));
+        self.metrics.record_request(
+            "json_rpc",
+            "simulateBundle",
+            metrics::json_rpc_outcome(&response, !matches!(id_check, Some(Err(_)))),
+            started.elapsed(),
+        );
+
+        let body = response.body.ok_or_else(|| {
+            anyhow!(
+                "Failed to decode simulateBundle response (HTTP {})",
+                response.status
+            )
+        })?;
+        if let Some(Err(e)) = id_check {
+            return Err(e);
+        }
+        parse_simulation_response(body)
+    }
+
+    // 模拟失败时返回错误，可作为 send_bundle 之前的预检步骤
+    pub async fn ensure_success(
+        &self,
+        transactions: &[VersionedTransaction],
+        config: &SimulateBundleConfig,
+    ) -> Result<SimulateBundleResult> {
+        let result = self.simulate_bundle(transactions, config).await?;
+        match result.failure_reason() {
+            Some(reason) => Err(anyhow!("Bundle simulation failed: {}", reason)),
+            None => Ok(result),
+        }
+    }
+}
+
+// 每笔交易一项，未指定的交易填 null
+fn accounts_configs(accounts: &[Option<Vec<Pubkey>>], len: usize) -> Result<Vec<Value>> {
+    if accounts.len() > len {
+        return Err(anyhow!(
+            "Got {} account configs for {} transactions",
+            accounts.len(),
+            len
+        ));
+    }
+    Ok((0..len)
+        .map(|i| match accounts.get(i) {
+            Some(Some(addresses)) => json!({
+                "encoding": Encoding::Base64.as_str(),
+                "addresses": addresses.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+            }),
+            _ => Value::Null,
+        })
+        .collect())
+}
+
+fn parse_simulation_response(body: Value) -> Result<SimulateBundleResult> {
+    if let Some(error) = body.get("error") {
+        let error_msg = error
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("Unknown error");
+        return Err(anyhow!("simulateBundle failed: {}", error_msg));
+    }
+
+    let result = body
+        .get("result")
+        .ok_or_else(|| anyhow!("Missing result in simulateBundle response"))?;
+    let slot = result["context"]["slot"].as_u64().unwrap_or_default();
+    let value = result
+        .get("value")
+        .ok_or_else(|| anyhow!("Missing value in simulateBundle response"))?;
+
+    let summary = match &value["summary"] {
+        Value::String(s) if s == "succeeded" => SimulationSummary::Succeeded,
+        summary => {
+            let failed = summary.get("failed").unwrap_or(summary);
+            SimulationSummary::Failed {
+                error: failed.get("error").cloned().unwrap_or_else(|| failed.clone()),
+                tx_signature: failed
+                    .get("tx_signature")
+                    .and_then(|s| s.as_str())
+                    .map(String::from),
+            }
+        }
+    };
+
+    let transaction_results = serde_json::from_value(
+        value
+            .get("transactionResults")
+            .cloned()
+            .unwrap_or_else(|| json!([])),
+    )
+    .map_err(|e| anyhow!("Failed to parse transaction results: {}", e))?;
+
+    Ok(SimulateBundleResult {
+        slot,
+        summary,
+        transaction_results,
+    })
+}
@@ -0,0 +1,297 @@
+use anyhow::{anyhow, Result};
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    instruction::{CompiledInstruction, Instruction},
+    message::{
+        v0::{self, LoadedAddresses},
+        MessageHeader, VersionedMessage,
+    },
+    pubkey::Pubkey,
+    signature::Signature,
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+// 构造向 Jito tip account 转账的指令
+pub fn tip_instruction_to(payer: &Pubkey, tip_account: &Pubkey, lamports: u64) -> Instruction {
+    system_instruction::transfer(payer, tip_account, lamports)
+}
+
+// 在签名前把 tip 指令追加到已有的交易末尾
+// 消息改变后原有签名失效，会被重置为默认值，需要调用方重新签名
+pub fn append_tip_to_transaction(tx: &mut Transaction, tip_ix: &Instruction) -> Result<()> {
+    let message = &mut tx.message;
+    append_instruction(
+        &mut message.header,
+        &mut message.account_keys,
+        &mut message.instructions,
+        &LoadedAddresses::default(),
+        tip_ix,
+    )?;
+    tx.signatures = vec![Signature::default(); message.header.num_required_signatures as usize];
+    Ok(())
+}
+
+// VersionedTransaction 版本，同时支持 legacy 和 v0 消息
+// 使用地址查找表的 v0 交易需要通过 append_tip_to_v0_transaction 传入查找表
+pub fn append_tip_to_versioned_transaction(
+    tx: &mut VersionedTransaction,
+    tip_ix: &Instruction,
+) -> Result<()> {
+    append_tip(tx, tip_ix, None)
+}
+
+// v0 交易版本：tip 涉及的账户已由查找表加载时复用加载的索引，不会重复加入静态账户
+pub fn append_tip_to_v0_transaction(
+    tx: &mut VersionedTransaction,
+    tip_ix: &Instruction,
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<()> {
+    append_tip(tx, tip_ix, Some(lookup_tables))
+}
+
+fn append_tip(
+    tx: &mut VersionedTransaction,
+    tip_ix: &Instruction,
+    lookup_tables: Option<&[AddressLookupTableAccount]>,
+) -> Result<()> {
+    let header = match &mut tx.message {
+        VersionedMessage::Legacy(message) => {
+            append_instruction(
+                &mut message.header,
+                &mut message.account_keys,
+                &mut message.instructions,
+                &LoadedAddresses::default(),
+                tip_ix,
+            )?;
+            message.header
+        }
+        VersionedMessage::V0(message) => {
+            let loaded = match lookup_tables {
+                Some(tables) => loaded_addresses(message, tables)?,
+                None if message.address_table_lookups.is_empty() => LoadedAddresses::default(),
+                None => {
+                    return Err(anyhow!(
+                        "Transaction uses address lookup tables, use append_tip_to_v0_transaction"
+                    ))
+                }
+            };
+            append_instruction(
+                &mut message.header,
+                &mut message.account_keys,
+                &mut message.instructions,
+                &loaded,
+                tip_ix,
+            )?;
+            message.header
+        }
+    };
+    tx.signatures = vec![Signature::default(); header.num_required_signatures as usize];
+    Ok(())
+}
+
+// 按 v0 消息的查找表索引解析加载的账户，顺序与运行时一致：所有 writable 在前，readonly 在后
+fn loaded_addresses(
+    message: &v0::Message,
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<LoadedAddresses> {
+    message
+        .address_table_lookups
+        .iter()
+        .map(|lookup| {
+            let table = lookup_tables
+                .iter()
+                .find(|table| table.key == lookup.account_key)
+                .ok_or_else(|| anyhow!("Lookup table {} not provided", lookup.account_key))?;
+            let resolve = |indexes: &[u8]| {
+                indexes
+                    .iter()
+                    .map(|index| {
+                        table
+                            .addresses
+                            .get(*index as usize)
+                            .copied()
+                            .ok_or_else(|| {
+                                anyhow!(
+                                    "Lookup table {} has no address at index {}",
+                                    table.key,
+                                    index
+                                )
+                            })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            };
+            Ok(LoadedAddresses {
+                writable: resolve(&lookup.writable_indexes)?,
+                readonly: resolve(&lookup.readonly_indexes)?,
+            })
+        })
+        .collect()
+}
+
+// 账户在消息中的索引：先查静态账户，再查查找表加载的账户
+fn account_index(
+    account_keys: &[Pubkey],
+    loaded: &LoadedAddresses,
+    pubkey: &Pubkey,
+) -> Option<usize> {
+    let writable_start = account_keys.len();
+    let readonly_start = writable_start + loaded.writable.len();
+    account_keys
+        .iter()
+        .position(|key| key == pubkey)
+        .or_else(|| {
+            loaded
+                .writable
+                .iter()
+                .position(|key| key == pubkey)
+                .map(|i| writable_start + i)
+        })
+        .or_else(|| {
+            loaded
+                .readonly
+                .iter()
+                .position(|key| key == pubkey)
+                .map(|i| readonly_start + i)
+        })
+}
+
+// 直接在已编译的消息上追加指令，缺少的账户按 header 分区插入并修正已有索引
+// 不允许引入新的签名者，签名者必须已经在消息中
+fn append_instruction(
+    header: &mut MessageHeader,
+    account_keys: &mut Vec<Pubkey>,
+    instructions: &mut Vec<CompiledInstruction>,
+    loaded: &LoadedAddresses,
+    ix: &Instruction,
+) -> Result<()> {
+    // 程序必须是静态账户，不能由查找表加载
+    if !account_keys.contains(&ix.program_id)
+        && (loaded.writable.contains(&ix.program_id) || loaded.readonly.contains(&ix.program_id))
+    {
+        return Err(anyhow!(
+            "Program {} is loaded from a lookup table",
+            ix.program_id
+        ));
+    }
+    // 先插入所有缺少的账户，插入会移动已有索引，最后再统一计算索引
+    ensure_account(
+        header,
+        account_keys,
+        instructions,
+        loaded,
+        &ix.program_id,
+        false,
+    )?;
+    for meta in &ix.accounts {
+        if meta.is_signer {
+            let index = account_keys
+                .iter()
+                .take(header.num_required_signatures as usize)
+                .position(|key| key == &meta.pubkey)
+                .ok_or_else(|| anyhow!("Signer {} is not a signer of the message", meta.pubkey))?;
+            let writable_signers =
+                header.num_required_signatures - header.num_readonly_signed_accounts;
+            if meta.is_writable && index >= writable_signers as usize {
+                return Err(anyhow!(
+                    "Signer {} is read-only in the message",
+                    meta.pubkey
+                ));
+            }
+        } else {
+            ensure_account(
+                header,
+                account_keys,
+                instructions,
+                loaded,
+                &meta.pubkey,
+                meta.is_writable,
+            )?;
+        }
+    }
+
+    let index_of = |pubkey: &Pubkey| {
+        let index = account_index(account_keys, loaded, pubkey)
+            .ok_or_else(|| anyhow!("Account {} missing from message", pubkey))?;
+        u8::try_from(index).map_err(|_| anyhow!("Account index {} out of range", index))
+    };
+    let program_id_index = index_of(&ix.program_id)?;
+    let accounts = ix
+        .accounts
+        .iter()
+        .map(|meta| index_of(&meta.pubkey))
+        .collect::<Result<Vec<_>>>()?;
+    instructions.push(CompiledInstruction {
+        program_id_index,
+        accounts,
+        data: ix.data.clone(),
+    });
+    Ok(())
+}
+
+// 确保账户在消息中，不存在时插入到对应的非签名分区
+// 已由查找表加载的账户直接复用，不再加入静态账户，否则账户列表会重复
+pub(crate) fn ensure_account(
+    header: &mut MessageHeader,
+    account_keys: &mut Vec<Pubkey>,
+    instructions: &mut [CompiledInstruction],
+    loaded: &LoadedAddresses,
+    pubkey: &Pubkey,
+    is_writable: bool,
+) -> Result<()> {
+    let num_keys = account_keys.len();
+    let readonly_unsigned_start = num_keys - header.num_readonly_unsigned_accounts as usize;
+    if let Some(index) = account_keys.iter().position(|key| key == pubkey) {
+        let writable_signers =
+            (header.num_required_signatures - header.num_readonly_signed_accounts) as usize;
+        let is_key_writable = index < writable_signers
+            || (index >= header.num_required_signatures as usize
+                && index < readonly_unsigned_start);
+        if is_writable && !is_key_writable {
+            return Err(anyhow!("Account {} is read-only in the message", pubkey));
+        }
+        return Ok(());
+    }
+    if loaded.writable.contains(pubkey) {
+        return Ok(());
+    }
+    if loaded.readonly.contains(pubkey) {
+        if is_writable {
+            return Err(anyhow!(
+                "Account {} is loaded read-only from a lookup table",
+                pubkey
+            ));
+        }
+        return Ok(());
+    }
+
+    // 索引是 u8，静态账户和查找表加载的账户合计计算
+    if num_keys + loaded.len() >= u8::MAX as usize {
+        return Err(anyhow!("Too many accounts in message"));
+    }
+
+    let index = if is_writable {
+        readonly_unsigned_start
+    } else {
+        header.num_readonly_unsigned_accounts += 1;
+        num_keys
+    };
+    account_keys.insert(index, *pubkey);
+
+    // 插入点之后的索引（包括 v0 地址查找表加载的账户）整体后移一位
+    let shift = |i: &mut u8| -> Result<()> {
+        if *i as usize >= index {
+            *i = i
+                .checked_add(1)
+                .ok_or_else(|| anyhow!("Account index {} out of range", i))?;
+        }
+        Ok(())
+    };
+    for ix in instructions.iter_mut() {
+        shift(&mut ix.program_id_index)?;
+        for account in ix.accounts.iter_mut() {
+            shift(account)?;
+        }
+    }
+    Ok(())
+}
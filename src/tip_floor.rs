@@ -0,0 +1,147 @@
+use anyhow::{anyhow, Result};
+#[cfg(feature = "tip-stream")]
+use futures_util::{SinkExt, StreamExt};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+#[cfg(feature = "tip-stream")]
+use tokio::sync::mpsc;
+#[cfg(feature = "tip-stream")]
+use tokio_tungstenite::tungstenite::Message;
+
+pub const DEFAULT_TIP_FLOOR_URL: &str = "https://bundles.jito.wtf/api/v1/bundles/tip_floor";
+pub const DEFAULT_TIP_STREAM_URL: &str = "wss://bundles.jito.wtf/api/v1/bundles/tip_stream";
+
+// 与 solana_sdk::native_token::LAMPORTS_PER_SOL 相同，避免为此依赖 solana-sdk
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+// 最近落地 bundle 的 tip 分位数，单位为 SOL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TipFloorData {
+    pub time: String,
+    pub landed_tips_25th_percentile: f64,
+    pub landed_tips_50th_percentile: f64,
+    pub landed_tips_75th_percentile: f64,
+    pub landed_tips_95th_percentile: f64,
+    pub landed_tips_99th_percentile: f64,
+    pub ema_landed_tips_50th_percentile: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipPercentile {
+    P25,
+    P50,
+    P75,
+    P95,
+    P99,
+    Ema50,
+}
+
+impl TipFloorData {
+    // 指定分位数的 tip，单位为 SOL
+    pub fn percentile(&self, percentile: TipPercentile) -> f64 {
+        match percentile {
+            TipPercentile::P25 => self.landed_tips_25th_percentile,
+            TipPercentile::P50 => self.landed_tips_50th_percentile,
+            TipPercentile::P75 => self.landed_tips_75th_percentile,
+            TipPercentile::P95 => self.landed_tips_95th_percentile,
+            TipPercentile::P99 => self.landed_tips_99th_percentile,
+            TipPercentile::Ema50 => self.ema_landed_tips_50th_percentile,
+        }
+    }
+
+    // 指定分位数的 tip，换算为 lamports 并向上取整
+    pub fn percentile_lamports(&self, percentile: TipPercentile) -> u64 {
+        (self.percentile(percentile) * LAMPORTS_PER_SOL as f64).ceil() as u64
+    }
+}
+
+// 解析 REST 或 websocket 返回的数据，接口返回数组，取最新的一条
+fn parse_tip_floor(value: Value) -> Result<TipFloorData> {
+    let entry = match value {
+        Value::Array(entries) => entries
+            .into_iter()
+            .last()
+            .ok_or_else(|| anyhow!("Tip floor response is empty"))?,
+        entry => entry,
+    };
+    serde_json::from_value(entry).map_err(|e| anyhow!("Failed to parse tip floor: {}", e))
+}
+
+// Tip floor REST / websocket 客户端
+pub struct TipFloor {
+    rest_url: String,
+    #[cfg_attr(not(feature = "tip-stream"), allow(dead_code))]
+    stream_url: String, // 未启用 tip-stream 时不使用
+    client: Client,
+}
+
+impl Default for TipFloor {
+    fn default() -> Self {
+        Self::new(DEFAULT_TIP_FLOOR_URL, DEFAULT_TIP_STREAM_URL)
+    }
+}
+
+impl TipFloor {
+    pub fn new(rest_url: &str, stream_url: &str) -> Self {
+        Self {
+            rest_url: rest_url.to_string(),
+            stream_url: stream_url.to_string(),
+            client: Client::new(),
+        }
+    }
+
+    // 获取最新的 tip floor
+    pub async fn fetch(&self) -> Result<TipFloorData> {
+        let response = self
+            .client
+            .get(&self.rest_url)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Request error: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("Tip floor request failed with status {}", status));
+        }
+
+        let body = response
+            .json::<Value>()
+            .await
+            .map_err(|e| anyhow!("Request error: {}", e))?;
+        parse_tip_floor(body)
+    }
+
+    // 订阅 tip floor 推送，连接断开或解析失败时发送错误并结束
+    #[cfg(feature = "tip-stream")]
+    pub async fn subscribe(&self) -> Result<mpsc::Receiver<Result<TipFloorData>>> {
+        let (ws, _) = tokio_tungstenite::connect_async(self.stream_url.as_str())
+            .await
+            .map_err(|e| anyhow!("Failed to connect to tip stream: {}", e))?;
+        let (mut write, mut read) = ws.split();
+        let (tx, rx) = mpsc::channel(64);
+
+        tokio::spawn(async move {
+            while let Some(message) = read.next().await {
+                let update = match message {
+                    Ok(Message::Text(text)) => serde_json::from_str::<Value>(&text)
+                        .map_err(|e| anyhow!("Failed to parse tip floor: {}", e))
+                        .and_then(parse_tip_floor),
+                    Ok(Message::Ping(payload)) => {
+                        let _ = write.send(Message::Pong(payload)).await;
+                        continue;
+                    }
+                    Ok(Message::Close(_)) => break,
+                    Ok(_) => continue,
+                    Err(e) => Err(anyhow!("Tip stream error: {}", e)),
+                };
+                let is_err = update.is_err();
+                if tx.send(update).await.is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
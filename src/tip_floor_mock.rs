@@ -0,0 +1,118 @@
+use crate::tip_floor::{TipFloor, TipFloorData};
+use anyhow::Result;
+use futures_util::SinkExt;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Response};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+// 本地 mock，用于测试时替代 Jito 的 tip floor 服务
+// REST 返回当前设置的数据，websocket 推送通过 push 发出的数据
+pub struct MockTipFloorServer {
+    rest_addr: SocketAddr,
+    stream_addr: SocketAddr,
+    current: Arc<RwLock<TipFloorData>>,
+    updates: broadcast::Sender<TipFloorData>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockTipFloorServer {
+    pub async fn start(initial: TipFloorData) -> Result<Self> {
+        let rest_listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        rest_listener.set_nonblocking(true)?;
+        let stream_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let rest_addr = rest_listener.local_addr()?;
+        let stream_addr = stream_listener.local_addr()?;
+        let current = Arc::new(RwLock::new(initial));
+        let (updates, _) = broadcast::channel(64);
+
+        // REST 与 MockBlockEngine 一样使用 hyper，返回只含当前数据的数组
+        let rest_current = current.clone();
+        let make_service = make_service_fn(move |_| {
+            let current = rest_current.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_| {
+                    let current = current.clone();
+                    async move {
+                        let body = serde_json::to_string(&[current.read().await.clone()])
+                            .unwrap_or_default();
+                        Ok::<_, Infallible>(
+                            Response::builder()
+                                .header("Content-Type", "application/json")
+                                .body(Body::from(body))
+                                .unwrap(),
+                        )
+                    }
+                }))
+            }
+        });
+        let rest_server = hyper::Server::from_tcp(rest_listener)?.serve(make_service);
+        let rest_task = tokio::spawn(async move {
+            let _ = rest_server.await;
+        });
+
+        let stream_updates = updates.clone();
+        let stream_task = tokio::spawn(async move {
+            while let Ok((socket, _)) = stream_listener.accept().await {
+                let mut updates = stream_updates.subscribe();
+                tokio::spawn(async move {
+                    let Ok(mut ws) = tokio_tungstenite::accept_async(socket).await else {
+                        return;
+                    };
+                    while let Ok(update) = updates.recv().await {
+                        let text = serde_json::to_string(&[update]).unwrap_or_default();
+                        if ws.send(Message::Text(text)).await.is_err() {
+                            break;
+                        }
+                    }
+                    let _ = ws.close(None).await;
+                });
+            }
+        });
+
+        Ok(Self {
+            rest_addr,
+            stream_addr,
+            current,
+            updates,
+            tasks: vec![rest_task, stream_task],
+        })
+    }
+
+    pub fn rest_url(&self) -> String {
+        format!("http://{}/api/v1/bundles/tip_floor", self.rest_addr)
+    }
+
+    pub fn stream_url(&self) -> String {
+        format!("ws://{}/api/v1/bundles/tip_stream", self.stream_addr)
+    }
+
+    // 指向该 mock 的客户端
+    pub fn client(&self) -> TipFloor {
+        TipFloor::new(&self.rest_url(), &self.stream_url())
+    }
+
+    // 修改 REST 返回的数据
+    pub async fn set(&self, data: TipFloorData) {
+        *self.current.write().await = data;
+    }
+
+    // 更新数据并推送给所有 websocket 订阅者
+    pub async fn push(&self, data: TipFloorData) {
+        self.set(data.clone()).await;
+        let _ = self.updates.send(data);
+    }
+}
+
+impl Drop for MockTipFloorServer {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::tip_floor::{TipFloorData, TipPercentile};
+use anyhow::{anyhow, Result};
+
+// 计算 tip 时可用的上下文
+#[derive(Debug, Clone, Default)]
+pub struct TipContext {
+    pub tip_floor: Option<TipFloorData>, // 最近的 tip floor 数据
+    pub attempt: u32,                    // 第几次重试，首次发送为 0
+    pub expected_profit_lamports: Option<u64>, // 预期收益
+}
+
+// 可插拔的 tip 出价策略
+pub trait TipStrategy: Send + Sync {
+    fn tip_lamports(&self, ctx: &TipContext) -> Result<u64>;
+}
+
+impl<T: TipStrategy + ?Sized> TipStrategy for Box<T> {
+    fn tip_lamports(&self, ctx: &TipContext) -> Result<u64> {
+        (**self).tip_lamports(ctx)
+    }
+}
+
+// 固定金额
+#[derive(Debug, Clone, Copy)]
+pub struct FixedTip(pub u64);
+
+impl TipStrategy for FixedTip {
+    fn tip_lamports(&self, _ctx: &TipContext) -> Result<u64> {
+        Ok(self.0)
+    }
+}
+
+// 按最近落地 tip 的分位数出价，结果限制在 [min, max] 区间；min 大于 max 时以 max 为准
+#[derive(Debug, Clone, Copy)]
+pub struct PercentileTip {
+    pub percentile: TipPercentile,
+    pub min_lamports: u64,
+    pub max_lamports: u64,
+}
+
+impl PercentileTip {
+    pub fn new(percentile: TipPercentile, min_lamports: u64, max_lamports: u64) -> Self {
+        Self {
+            percentile,
+            min_lamports,
+            max_lamports,
+        }
+    }
+}
+
+impl TipStrategy for PercentileTip {
+    fn tip_lamports(&self, ctx: &TipContext) -> Result<u64> {
+        let tip_floor = ctx
+            .tip_floor
+            .as_ref()
+            .ok_or_else(|| anyhow!("Tip floor data is required for percentile tip"))?;
+        Ok(tip_floor
+            .percentile_lamports(self.percentile)
+            .max(self.min_lamports)
+            .min(self.max_lamports))
+    }
+}
+
+// 按预期收益的比例出价，不超过上限
+#[derive(Debug, Clone, Copy)]
+pub struct ProfitShareTip {
+    share: f64, // 0.0 ~ 1.0，只能通过 new 设置
+    pub max_lamports: u64,
+}
+
+impl ProfitShareTip {
+    pub fn new(share: f64, max_lamports: u64) -> Self {
+        Self {
+            share: share.clamp(0.0, 1.0),
+            max_lamports,
+        }
+    }
+
+    pub fn share(&self) -> f64 {
+        self.share
+    }
+}
+
+impl TipStrategy for ProfitShareTip {
+    fn tip_lamports(&self, ctx: &TipContext) -> Result<u64> {
+        let profit = ctx
+            .expected_profit_lamports
+            .ok_or_else(|| anyhow!("Expected profit is required for profit share tip"))?;
+        Ok(((profit as f64 * self.share) as u64).min(self.max_lamports))
+    }
+}
+
+// 每次重试按倍数提高内部策略的出价，不超过上限
+pub struct EscalatingTip<S> {
+    pub inner: S,
+    pub multiplier: f64,
+    pub max_lamports: u64,
+}
+
+impl<S: TipStrategy> EscalatingTip<S> {
+    pub fn new(inner: S, multiplier: f64, max_lamports: u64) -> Self {
+        Self {
+            inner,
+            multiplier,
+            max_lamports,
+        }
+    }
+}
+
+impl<S: TipStrategy> TipStrategy for EscalatingTip<S> {
+    fn tip_lamports(&self, ctx: &TipContext) -> Result<u64> {
+        let base = self.inner.tip_lamports(ctx)?;
+        // 重试次数超过 i32::MAX 时按 i32::MAX 计算，浮点数转 u64 时饱和，再限制在上限内
+        let attempt = i32::try_from(ctx.attempt).unwrap_or(i32::MAX);
+        let escalated = base as f64 * self.multiplier.powi(attempt);
+        Ok((escalated as u64).min(self.max_lamports))
+    }
+}
@@ -0,0 +1,306 @@
+use crate::metrics::{self, Metrics, MetricsSink};
+use crate::JitoJsonRpcSDK;
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// 状态查询接口每次最多 5 个 bundle id
+const MAX_IDS_PER_REQUEST: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleState {
+    Unknown,
+    Invalid,
+    Pending,
+    Landed,
+    Processed,
+    Confirmed,
+    Finalized,
+    Failed,
+    Expired, // 未落地且 blockhash 已失效
+}
+
+impl BundleState {
+    pub fn is_final(self) -> bool {
+        matches!(
+            self,
+            BundleState::Finalized | BundleState::Failed | BundleState::Expired
+        )
+    }
+
+    // 已经上链（不论确认级别）
+    pub fn is_landed(self) -> bool {
+        self.confirmation_level() > 0
+    }
+
+    // 上链后的确认级别，未上链为 0
+    fn confirmation_level(self) -> u8 {
+        match self {
+            BundleState::Landed => 1,
+            BundleState::Processed => 2,
+            BundleState::Confirmed => 3,
+            BundleState::Finalized => 4,
+            _ => 0,
+        }
+    }
+
+    // 状态只向前变化：结束的状态不再改变，没有查到状态 (Unknown) 不覆盖已知状态，
+    // 已上链的 bundle 不会回到未上链状态，确认级别也不会降低。
+    // bundle 超出状态查询窗口后，Jito 会把已确认的 bundle 报告为 Invalid 或不再返回
+    fn can_transition_to(self, next: BundleState) -> bool {
+        if self == next || self.is_final() || next == BundleState::Unknown {
+            return false;
+        }
+        !self.is_landed() || next.confirmation_level() > self.confirmation_level()
+    }
+}
+
+impl fmt::Display for BundleState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            BundleState::Unknown => "unknown",
+            BundleState::Invalid => "invalid",
+            BundleState::Pending => "pending",
+            BundleState::Landed => "landed",
+            BundleState::Processed => "processed",
+            BundleState::Confirmed => "confirmed",
+            BundleState::Finalized => "finalized",
+            BundleState::Failed => "failed",
+            BundleState::Expired => "expired",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TrackedBundle {
+    pub bundle_id: String,
+    pub state: BundleState,
+    pub slot: Option<u64>,
+    pub tip_lamports: u64,
+    pub last_valid_block_height: Option<u64>,
+    pub tracked_at: Instant,
+}
+
+// 一次状态变化
+#[derive(Debug, Clone)]
+pub struct BundleTransition {
+    pub bundle_id: String,
+    pub from: BundleState,
+    pub to: BundleState,
+    pub slot: Option<u64>,
+    pub elapsed: Duration, // 从开始跟踪到本次变化的时间
+}
+
+// 轮询 getInflightBundleStatuses / getBundleStatuses 跟踪 bundle 状态，并记录落地率和 tip 花费
+#[derive(Debug, Default)]
+pub struct BundleTracker {
+    bundles: Vec<TrackedBundle>,
+    pub(crate) metrics: Metrics,
+}
+
+impl BundleTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_metrics(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.metrics = Metrics::new(sink);
+        self
+    }
+
+    // 开始跟踪一个 bundle，tip_lamports 在落地时计入 tip 花费
+    pub fn track(&mut self, bundle_id: &str, tip_lamports: u64) {
+        self.insert(bundle_id, tip_lamports, None);
+    }
+
+    // 同 track，区块高度超过 last_valid_block_height 仍未落地时由 expire 标记为 Expired
+    pub fn track_until(
+        &mut self,
+        bundle_id: &str,
+        tip_lamports: u64,
+        last_valid_block_height: u64,
+    ) {
+        self.insert(bundle_id, tip_lamports, Some(last_valid_block_height));
+    }
+
+    fn insert(&mut self, bundle_id: &str, tip_lamports: u64, last_valid_block_height: Option<u64>) {
+        // 重新提交的 bundle 从头跟踪
+        if let Some(bundle) = self.bundles.iter_mut().find(|b| b.bundle_id == bundle_id) {
+            if bundle.state == BundleState::Failed {
+                bundle.state = BundleState::Unknown;
+            }
+            return;
+        }
+        self.bundles.push(TrackedBundle {
+            bundle_id: bundle_id.to_string(),
+            state: BundleState::Unknown,
+            slot: None,
+            tip_lamports,
+            last_valid_block_height,
+            tracked_at: Instant::now(),
+        });
+        self.metrics
+            .increment_counter(metrics::BUNDLES_TRACKED_TOTAL, &[], 1);
+    }
+
+    pub fn get(&self, bundle_id: &str) -> Option<&TrackedBundle> {
+        self.bundles.iter().find(|b| b.bundle_id == bundle_id)
+    }
+
+    pub fn bundles(&self) -> &[TrackedBundle] {
+        &self.bundles
+    }
+
+    // 所有 bundle 都已 finalized 或 failed
+    pub fn is_done(&self) -> bool {
+        self.bundles.iter().all(|b| b.state.is_final())
+    }
+
+    // 查询未结束的 bundle，返回本轮的状态变化
+    pub async fn poll(&mut self, sdk: &JitoJsonRpcSDK) -> Result<Vec<BundleTransition>> {
+        let pending = self
+            .bundles
+            .iter()
+            .filter(|b| !b.state.is_final())
+            .map(|b| b.bundle_id.clone())
+            .collect::<Vec<_>>();
+
+        let mut transitions = Vec::new();
+        for chunk in pending.chunks(MAX_IDS_PER_REQUEST) {
+            for (id, state, slot) in query_states(sdk, chunk).await? {
+                if let Some(transition) = self.update(&id, state, slot) {
+                    transitions.push(transition);
+                }
+            }
+        }
+        Ok(transitions)
+    }
+
+    // 按当前区块高度（例如 BlockhashManager::block_height）把 blockhash 已失效且未落地的 bundle 标记为 Expired，
+    // 应在 poll 之后调用，避免把刚落地的 bundle 误判为过期
+    pub fn expire(&mut self, block_height: u64) -> Vec<BundleTransition> {
+        let expired = self
+            .bundles
+            .iter()
+            .filter(|b| !b.state.is_final() && !b.state.is_landed())
+            .filter(|b| matches!(b.last_valid_block_height, Some(h) if block_height > h))
+            .map(|b| b.bundle_id.clone())
+            .collect::<Vec<_>>();
+        expired
+            .iter()
+            .filter_map(|id| self.update(id, BundleState::Expired, None))
+            .collect()
+    }
+
+    fn update(
+        &mut self,
+        bundle_id: &str,
+        state: BundleState,
+        slot: Option<u64>,
+    ) -> Option<BundleTransition> {
+        let bundle = self.bundles.iter_mut().find(|b| b.bundle_id == bundle_id)?;
+        if !bundle.state.can_transition_to(state) {
+            return None;
+        }
+        let from = bundle.state;
+        let elapsed = bundle.tracked_at.elapsed();
+        bundle.state = state;
+        bundle.slot = slot.or(bundle.slot);
+
+        if state.is_landed() && !from.is_landed() {
+            self.metrics.record_histogram(
+                metrics::BUNDLE_LANDING_SECONDS,
+                &[],
+                elapsed.as_secs_f64(),
+            );
+            self.metrics
+                .increment_counter(metrics::TIP_LAMPORTS_TOTAL, &[], bundle.tip_lamports);
+        }
+        if state.is_final() || state == BundleState::Invalid {
+            self.metrics.increment_counter(
+                metrics::BUNDLE_RESULTS_TOTAL,
+                &[("state", state.to_string())],
+                1,
+            );
+        }
+
+        Some(BundleTransition {
+            bundle_id: bundle_id.to_string(),
+            from,
+            to: state,
+            slot: bundle.slot,
+            elapsed,
+        })
+    }
+}
+
+// 查询一组 bundle 的当前状态；已落地的 bundle 再通过 getBundleStatuses 获取确认级别
+async fn query_states(
+    sdk: &JitoJsonRpcSDK,
+    bundle_ids: &[String],
+) -> Result<Vec<(String, BundleState, Option<u64>)>> {
+    let inflight = sdk
+        .get_in_flight_bundle_statuses(bundle_ids.to_vec())
+        .await?;
+    if let Some(error) = inflight.get("error") {
+        return Err(anyhow!("getInflightBundleStatuses failed: {}", error));
+    }
+    let mut states = bundle_ids
+        .iter()
+        .map(|id| {
+            let status = inflight["result"]["value"]
+                .as_array()
+                .and_then(|values| values.iter().find(|v| v["bundle_id"] == id.as_str()))
+                .cloned()
+                .unwrap_or(Value::Null);
+            let state = match status["status"].as_str() {
+                Some("Pending") => BundleState::Pending,
+                Some("Landed") => BundleState::Landed,
+                Some("Failed") => BundleState::Failed,
+                Some("Invalid") => BundleState::Invalid,
+                _ => BundleState::Unknown,
+            };
+            (id.clone(), state, status["landed_slot"].as_u64())
+        })
+        .collect::<Vec<_>>();
+
+    let landed = states
+        .iter()
+        .filter(|(_, state, _)| *state == BundleState::Landed)
+        .map(|(id, _, _)| id.clone())
+        .collect::<Vec<_>>();
+    if landed.is_empty() {
+        return Ok(states);
+    }
+
+    let statuses = sdk.get_bundle_statuses(landed).await?;
+    for status in statuses["result"]["value"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+    {
+        let Some(id) = status["bundle_id"].as_str() else {
+            continue;
+        };
+        // 成功时 err 为 {"Ok": null}
+        let failed = match &status["err"] {
+            Value::Null => false,
+            err => err.get("Ok").is_none(),
+        };
+        let state = match status["confirmation_status"].as_str() {
+            _ if failed => BundleState::Failed,
+            Some("processed") => BundleState::Processed,
+            Some("confirmed") => BundleState::Confirmed,
+            Some("finalized") => BundleState::Finalized,
+            _ => BundleState::Landed,
+        };
+        if let Some(entry) = states.iter_mut().find(|(watched, _, _)| watched == id) {
+            entry.1 = state;
+            entry.2 = status["slot"].as_u64().or(entry.2);
+        }
+    }
+    Ok(states)
+}
@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<TransportResponse>> + Send + 'a>>;
+
+// HTTP 响应：状态码和解析后的 JSON，响应体不是合法 JSON 时 body 为 None
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub body: Option<Value>,
+}
+
+impl TransportResponse {
+    pub fn new(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            body: Some(body),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == 429
+    }
+}
+
+// JitoJsonRpcSDK 发送 JSON-RPC 请求所用的传输层，可替换为内存实现、自定义连接池或记录请求的实现。
+// 只有连接失败、超时等无法得到响应的情况才返回 Err。
+pub trait JsonRpcTransport: Send + Sync {
+    fn post<'a>(&'a self, url: &'a str, body: &'a Value) -> TransportFuture<'a>;
+}
+
+// 默认的 reqwest 实现
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl JsonRpcTransport for ReqwestTransport {
+    fn post<'a>(&'a self, url: &'a str, body: &'a Value) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .post(url)
+                .header("Content-Type", "application/json")
+                .json(body)
+                .send()
+                .await
+                .map_err(|e| anyhow!("{}", e))?;
+            let status = response.status().as_u16();
+            let body = response.json::<Value>().await.ok();
+            Ok(TransportResponse { status, body })
+        })
+    }
+}
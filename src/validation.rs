@@ -0,0 +1,352 @@
+use crate::bundle::MAX_BUNDLE_TRANSACTIONS;
+use crate::dontfront::is_front_run_protected;
+use crate::nonce::{advance_nonce_index, uses_durable_nonce};
+use solana_sdk::{
+    hash::Hash,
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::Signature,
+    system_instruction::SystemInstruction,
+    system_program,
+    transaction::VersionedTransaction,
+};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+// Jito 主网 tip accounts
+pub const MAINNET_TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+// Jito 拒绝 tip 低于该值的 bundle
+pub const MIN_TIP_LAMPORTS: u64 = 1_000;
+
+// tip 不在最后一笔交易时的处理方式；放在最后一笔交易可保证只在整个 bundle 落地时才支付
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TipPlacement {
+    Allow,
+    #[default]
+    Warn,
+    Error,
+}
+
+pub fn mainnet_tip_accounts() -> Vec<Pubkey> {
+    MAINNET_TIP_ACCOUNTS
+        .iter()
+        .map(|account| Pubkey::from_str(account).expect("valid tip account"))
+        .collect()
+}
+
+// Bundle 校验失败的原因，index 为交易在 bundle 中的位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleValidationError {
+    EmptyBundle,
+    TooManyTransactions { count: usize },
+    MissingSignature { index: usize },
+    InvalidSignature { index: usize },
+    DuplicateSignature { index: usize, signature: Signature },
+    TransactionTooLarge { index: usize, size: usize },
+    InconsistentBlockhash { index: usize, expected: Hash, found: Hash },
+    StaleBlockhash { expected: Hash, found: Hash },
+    MissingTip,
+    TipInRevertibleTransaction { index: usize },
+    MisplacedAdvanceNonce { index: usize, instruction_index: usize },
+    TipTooLow { lamports: u64, minimum: u64 },
+    TipNotInLastTransaction { index: usize },
+    FrontRunProtectedNotFirst { index: usize },
+}
+
+impl fmt::Display for BundleValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyBundle => write!(f, "Bundle must contain at least one transaction"),
+            Self::TooManyTransactions { count } => write!(
+                f,
+                "Bundle can contain at most {} transactions, got {}",
+                MAX_BUNDLE_TRANSACTIONS, count
+            ),
+            Self::MissingSignature { index } => {
+                write!(f, "Transaction {} is not fully signed", index)
+            }
+            Self::InvalidSignature { index } => {
+                write!(f, "Transaction {} has an invalid signature", index)
+            }
+            Self::DuplicateSignature { index, signature } => {
+                write!(f, "Transaction {} duplicates signature {}", index, signature)
+            }
+            Self::TransactionTooLarge { index, size } => write!(
+                f,
+                "Transaction {} is {} bytes, exceeds packet limit of {} bytes",
+                index, size, PACKET_DATA_SIZE
+            ),
+            Self::InconsistentBlockhash {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Transaction {} uses blockhash {}, expected {}",
+                index, found, expected
+            ),
+            Self::StaleBlockhash { expected, found } => write!(
+                f,
+                "Bundle uses blockhash {}, latest is {}",
+                found, expected
+            ),
+            Self::MissingTip => write!(f, "Bundle does not transfer a tip to a Jito tip account"),
+            Self::TipInRevertibleTransaction { index } => write!(
+                f,
+                "Transaction {} pays a tip but is allowed to fail independently",
+                index
+            ),
+            Self::MisplacedAdvanceNonce {
+                index,
+                instruction_index,
+            } => write!(
+                f,
+                "Transaction {} advances a nonce in instruction {}, must be the first instruction",
+                index, instruction_index
+            ),
+            Self::TipTooLow { lamports, minimum } => write!(
+                f,
+                "Bundle tips {} lamports, minimum is {} lamports",
+                lamports, minimum
+            ),
+            Self::TipNotInLastTransaction { index } => write!(
+                f,
+                "Transaction {} pays a tip, tips should be in the last transaction",
+                index
+            ),
+            Self::FrontRunProtectedNotFirst { index } => write!(
+                f,
+                "Transaction {} is protected by jitodontfront and must be the first transaction",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BundleValidationError {}
+
+// 交易中向 tip account 转账的指令
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TipTransfer {
+    pub instruction_index: usize,
+    pub from: Pubkey,
+    pub tip_account: Pubkey,
+    pub lamports: u64,
+}
+
+// 解码交易中所有转到 tip account 的 system transfer
+// 通过地址查找表加载的账户无法解析，会被忽略
+pub fn find_tip_transfers(tx: &VersionedTransaction, tip_accounts: &[Pubkey]) -> Vec<TipTransfer> {
+    let keys = tx.message.static_account_keys();
+    tx.message
+        .instructions()
+        .iter()
+        .enumerate()
+        .filter_map(|(instruction_index, ix)| {
+            if keys.get(ix.program_id_index as usize) != Some(&system_program::id()) {
+                return None;
+            }
+            let lamports = match bincode::deserialize::<SystemInstruction>(&ix.data) {
+                Ok(SystemInstruction::Transfer { lamports }) => lamports,
+                _ => return None,
+            };
+            let from = *keys.get(*ix.accounts.first()? as usize)?;
+            let to = *keys.get(*ix.accounts.get(1)? as usize)?;
+            tip_accounts.contains(&to).then_some(TipTransfer {
+                instruction_index,
+                from,
+                tip_account: to,
+                lamports,
+            })
+        })
+        .collect()
+}
+
+// 基于已解码交易的 bundle 预检
+pub struct BundleValidator {
+    tip_accounts: Vec<Pubkey>,
+    latest_blockhash: Option<Hash>,  // 设置后要求 bundle 使用该 blockhash
+    revertible: HashSet<Signature>,  // 允许单独失败的交易
+    min_tip_lamports: u64,
+    tip_placement: TipPlacement,
+}
+
+impl Default for BundleValidator {
+    fn default() -> Self {
+        Self::new(mainnet_tip_accounts())
+    }
+}
+
+impl BundleValidator {
+    pub fn new(tip_accounts: Vec<Pubkey>) -> Self {
+        Self {
+            tip_accounts,
+            latest_blockhash: None,
+            revertible: HashSet::new(),
+            min_tip_lamports: MIN_TIP_LAMPORTS,
+            tip_placement: TipPlacement::default(),
+        }
+    }
+
+    pub fn latest_blockhash(mut self, blockhash: Hash) -> Self {
+        self.latest_blockhash = Some(blockhash);
+        self
+    }
+
+    pub fn revertible(mut self, signature: Signature) -> Self {
+        self.revertible.insert(signature);
+        self
+    }
+
+    // 最低 tip 总额，默认 MIN_TIP_LAMPORTS
+    pub fn min_tip_lamports(mut self, lamports: u64) -> Self {
+        self.min_tip_lamports = lamports;
+        self
+    }
+
+    pub fn tip_placement(mut self, placement: TipPlacement) -> Self {
+        self.tip_placement = placement;
+        self
+    }
+
+    // bundle 中转到 tip account 的 lamports 总额
+    pub fn tip_lamports(&self, transactions: &[VersionedTransaction]) -> u64 {
+        transactions
+            .iter()
+            .flat_map(|tx| find_tip_transfers(tx, &self.tip_accounts))
+            .fold(0u64, |total, tip| total.saturating_add(tip.lamports))
+    }
+
+    pub fn tip_accounts(&self) -> &[Pubkey] {
+        &self.tip_accounts
+    }
+
+    pub fn validate(&self, transactions: &[VersionedTransaction]) -> Result<(), BundleValidationError> {
+        if transactions.is_empty() {
+            return Err(BundleValidationError::EmptyBundle);
+        }
+        if transactions.len() > MAX_BUNDLE_TRANSACTIONS {
+            return Err(BundleValidationError::TooManyTransactions {
+                count: transactions.len(),
+            });
+        }
+
+        let mut seen = HashSet::new();
+        for (index, tx) in transactions.iter().enumerate() {
+            // 每笔交易至少需要付款人的签名
+            let required = tx.message.header().num_required_signatures as usize;
+            if required == 0
+                || tx.signatures.len() != required
+                || tx.signatures.iter().any(|s| *s == Signature::default())
+            {
+                return Err(BundleValidationError::MissingSignature { index });
+            }
+            if !tx.verify_with_results().into_iter().all(|ok| ok) {
+                return Err(BundleValidationError::InvalidSignature { index });
+            }
+            for signature in &tx.signatures {
+                if !seen.insert(*signature) {
+                    return Err(BundleValidationError::DuplicateSignature {
+                        index,
+                        signature: *signature,
+                    });
+                }
+            }
+
+            // 带 jitodontfront 账户的交易只能位于 bundle 第一位
+            if index != 0 && is_front_run_protected(tx) {
+                return Err(BundleValidationError::FrontRunProtectedNotFirst { index });
+            }
+
+            if let Some(instruction_index) = advance_nonce_index(tx).filter(|i| *i != 0) {
+                return Err(BundleValidationError::MisplacedAdvanceNonce {
+                    index,
+                    instruction_index,
+                });
+            }
+
+            let size = bincode::serialized_size(tx).unwrap_or(u64::MAX) as usize;
+            if size > PACKET_DATA_SIZE {
+                return Err(BundleValidationError::TransactionTooLarge { index, size });
+            }
+        }
+
+        // 使用 durable nonce 的交易以 nonce 值作为 blockhash，不参与一致性和过期检查
+        let mut with_blockhash = transactions
+            .iter()
+            .enumerate()
+            .filter(|(_, tx)| !uses_durable_nonce(tx));
+        if let Some((_, first)) = with_blockhash.next() {
+            let expected = *first.message.recent_blockhash();
+            for (index, tx) in with_blockhash {
+                let found = *tx.message.recent_blockhash();
+                if found != expected {
+                    return Err(BundleValidationError::InconsistentBlockhash {
+                        index,
+                        expected,
+                        found,
+                    });
+                }
+            }
+            if let Some(latest) = self.latest_blockhash {
+                if latest != expected {
+                    return Err(BundleValidationError::StaleBlockhash {
+                        expected: latest,
+                        found: expected,
+                    });
+                }
+            }
+        }
+
+        let mut has_tip = false;
+        let last = transactions.len() - 1;
+        for (index, tx) in transactions.iter().enumerate() {
+            if find_tip_transfers(tx, &self.tip_accounts).is_empty() {
+                continue;
+            }
+            if tx
+                .signatures
+                .first()
+                .is_some_and(|signature| self.revertible.contains(signature))
+            {
+                return Err(BundleValidationError::TipInRevertibleTransaction { index });
+            }
+            if index != last {
+                match self.tip_placement {
+                    TipPlacement::Allow => {}
+                    TipPlacement::Warn => tracing::warn!(
+                        "{}",
+                        BundleValidationError::TipNotInLastTransaction { index }
+                    ),
+                    TipPlacement::Error => {
+                        return Err(BundleValidationError::TipNotInLastTransaction { index })
+                    }
+                }
+            }
+            has_tip = true;
+        }
+        if !has_tip {
+            return Err(BundleValidationError::MissingTip);
+        }
+        let lamports = self.tip_lamports(transactions);
+        if lamports < self.min_tip_lamports {
+            return Err(BundleValidationError::TipTooLow {
+                lamports,
+                minimum: self.min_tip_lamports,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{anyhow, Result};
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::signers::Signers,
+    transaction::VersionedTransaction,
+};
+
+// 使用调用方提供的地址查找表编译 v0 消息
+pub fn compile_v0_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedMessage> {
+    let message = v0::Message::try_compile(payer, instructions, lookup_tables, recent_blockhash)
+        .map_err(|e| anyhow!("Failed to compile v0 message: {}", e))?;
+    Ok(VersionedMessage::V0(message))
+}
+
+// 构造未签名的 v0 交易，签名为默认值，可以继续追加 tip 后再签名
+pub fn unsigned_v0_transaction(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedTransaction> {
+    let message = compile_v0_message(payer, instructions, lookup_tables, recent_blockhash)?;
+    let transaction = VersionedTransaction {
+        signatures: vec![Signature::default(); message.header().num_required_signatures as usize],
+        message,
+    };
+    check_transaction_size(&transaction)?;
+    Ok(transaction)
+}
+
+// 构造并签名 v0 交易
+pub fn build_v0_transaction<T: Signers + ?Sized>(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+    signers: &T,
+) -> Result<VersionedTransaction> {
+    let message = compile_v0_message(payer, instructions, lookup_tables, recent_blockhash)?;
+    let transaction = VersionedTransaction::try_new(message, signers)
+        .map_err(|e| anyhow!("Failed to sign transaction: {}", e))?;
+    check_transaction_size(&transaction)?;
+    Ok(transaction)
+}
+
+// 交易序列化后的大小，签名数按 header 计算，未签名的交易也能得到准确结果
+pub fn transaction_size(transaction: &VersionedTransaction) -> usize {
+    let signatures = transaction.message.header().num_required_signatures as usize;
+    let placeholder = VersionedTransaction {
+        signatures: vec![Signature::default(); signatures],
+        message: transaction.message.clone(),
+    };
+    bincode::serialized_size(&placeholder).unwrap_or(u64::MAX) as usize
+}
+
+// 检查交易是否超过单个 packet 的大小限制（1232 字节），包括地址查找表带来的开销
+pub fn check_transaction_size(transaction: &VersionedTransaction) -> Result<usize> {
+    let size = transaction_size(transaction);
+    if size > PACKET_DATA_SIZE {
+        let lookup_tables = transaction
+            .message
+            .address_table_lookups()
+            .map(|lookups| lookups.len())
+            .unwrap_or_default();
+        return Err(anyhow!(
+            "Transaction is {} bytes with {} lookup tables, exceeds packet limit of {} bytes",
+            size,
+            lookup_tables,
+            PACKET_DATA_SIZE
+        ));
+    }
+    Ok(size)
+}
@@ -0,0 +1,105 @@
+use jito_sdk_rust::{
+    BlockhashManager, BundleBuilder, BundleState, BundleTracker, MockBlockEngine, TipContext,
+};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn manager_fetches_blockhash_and_block_height() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let manager = BlockhashManager::new(&mock.solana_rpc_url());
+    assert!(manager.latest().is_none());
+    assert!(manager.blockhash().is_err());
+
+    let latest = manager.refresh().await.unwrap();
+    assert_eq!(manager.latest(), Some(latest));
+    assert_eq!(latest.last_valid_block_height, latest.slot + 150);
+    assert!(manager.block_height().unwrap() >= latest.slot);
+    assert!(manager.is_valid(latest.last_valid_block_height));
+    assert!(!manager.is_valid(latest.slot - 1));
+
+    let methods = mock
+        .requests()
+        .into_iter()
+        .map(|r| r.method)
+        .collect::<Vec<_>>();
+    assert_eq!(methods, vec!["getLatestBlockhash", "getBlockHeight"]);
+}
+
+#[tokio::test]
+async fn manager_refreshes_in_background() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_slot_duration(Duration::from_millis(5));
+    let manager = BlockhashManager::new(&mock.solana_rpc_url())
+        .with_refresh_interval(Duration::from_millis(20))
+        .start()
+        .await
+        .unwrap();
+    let first = manager.blockhash().unwrap();
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let latest = manager.blockhash().unwrap();
+    assert!(latest.slot > first.slot);
+    assert_ne!(latest.blockhash, first.blockhash);
+}
+
+#[tokio::test]
+async fn manager_reports_rpc_errors() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let url = mock.json_rpc_url().replace("/api/v1", "/unknown");
+    assert!(BlockhashManager::new(&url).start().await.is_err());
+}
+
+#[tokio::test]
+async fn builder_uses_managed_blockhash() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let manager = BlockhashManager::new(&mock.solana_rpc_url());
+    let latest = manager.refresh().await.unwrap();
+
+    let payer = Keypair::new();
+    let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::default(),
+    );
+    let builder = BundleBuilder::new(payer.pubkey(), Pubkey::new_unique())
+        .add_transaction(tx)
+        .blockhash(latest);
+    assert_eq!(
+        builder.last_valid_block_height(),
+        Some(latest.last_valid_block_height)
+    );
+
+    let transactions = builder.build(&TipContext::default()).unwrap();
+    assert_eq!(
+        *transactions[0].message.recent_blockhash(),
+        latest.blockhash
+    );
+    assert!(transactions[0].verify_with_results().iter().all(|ok| !ok));
+}
+
+#[test]
+fn tracker_expires_bundles_past_last_valid_block_height() {
+    let mut tracker = BundleTracker::new();
+    tracker.track_until("old", 0, 100);
+    tracker.track_until("new", 0, 200);
+    tracker.track("untimed", 0);
+
+    let transitions = tracker.expire(150);
+    assert_eq!(transitions.len(), 1);
+    assert_eq!(transitions[0].bundle_id, "old");
+    assert_eq!(transitions[0].to, BundleState::Expired);
+    assert_eq!(tracker.get("new").unwrap().state, BundleState::Unknown);
+    assert_eq!(tracker.get("untimed").unwrap().state, BundleState::Unknown);
+
+    // 已结束的 bundle 不会再次变化
+    assert!(tracker.expire(300).iter().all(|t| t.bundle_id != "old"));
+}
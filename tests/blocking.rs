@@ -0,0 +1,54 @@
+use jito_sdk_rust::blocking::{GrpcClient, JitoJsonRpcSDK};
+use jito_sdk_rust::MockBlockEngine;
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+// mock 运行在独立的多线程 runtime 上，测试线程本身不在异步上下文中
+fn start_mock() -> (tokio::runtime::Runtime, MockBlockEngine) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mock = runtime.block_on(MockBlockEngine::start()).unwrap();
+    (runtime, mock)
+}
+
+fn transfer_tx(payer: &Keypair, lamports: u64) -> VersionedTransaction {
+    let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports);
+    Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], Hash::default())
+        .into()
+}
+
+#[test]
+fn blocking_json_rpc_round_trip() {
+    let (_runtime, mock) = start_mock();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None).unwrap();
+    let payer = Keypair::new();
+
+    let tip_account = sdk.random_tip_pubkey().unwrap();
+    assert!(jito_sdk_rust::validation::mainnet_tip_accounts().contains(&tip_account));
+
+    let response = sdk
+        .send_bundle_transactions(&[transfer_tx(&payer, 1), transfer_tx(&payer, 2)], None)
+        .unwrap();
+    let bundle_id = response["result"].as_str().unwrap().to_string();
+    assert_eq!(mock.bundle_ids(), vec![bundle_id.clone()]);
+
+    let statuses = sdk.get_in_flight_bundle_statuses(vec![bundle_id]).unwrap();
+    assert!(statuses["result"]["value"][0]["status"].is_string());
+}
+
+#[test]
+fn blocking_grpc_round_trip() {
+    let (_runtime, mock) = start_mock();
+    mock.set_regions(vec!["ny".to_string(), "amsterdam".to_string()]);
+    let client = GrpcClient::connect(&mock.grpc_url()).unwrap();
+
+    assert_eq!(client.get_regions().unwrap().available_regions.len(), 2);
+    let uuid = client
+        .send_bundle(vec![transfer_tx(&Keypair::new(), 1)])
+        .unwrap();
+    assert_eq!(mock.bundle_ids(), vec![uuid]);
+}
@@ -0,0 +1,373 @@
+use jito_sdk_rust::validation::mainnet_tip_accounts;
+use jito_sdk_rust::{
+    append_tip_to_transaction, append_tip_to_v0_transaction, append_tip_to_versioned_transaction,
+    build_v0_transaction,
+    dont_front_account, is_front_run_protected, protect_transaction, tip_instruction_to,
+    unsigned_v0_transaction, BundleBuilder, BundleValidationError, BundleValidator, FixedTip,
+    TipContext, TipPlacement,
+};
+use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, VersionedMessage},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+fn signed(payer: &Keypair, ixs: &[Instruction]) -> VersionedTransaction {
+    Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &[payer], Hash::default()).into()
+}
+
+#[test]
+fn append_tip_matches_freshly_compiled_transaction() {
+    let payer = Keypair::new();
+    let receiver = Pubkey::new_unique();
+    let tip_account = mainnet_tip_accounts()[0];
+    let memo = Instruction::new_with_bytes(Pubkey::new_unique(), b"memo", vec![]);
+    let transfer = system_instruction::transfer(&payer.pubkey(), &receiver, 5);
+    let tip = tip_instruction_to(&payer.pubkey(), &tip_account, 1_000);
+
+    let mut tx = Transaction::new_with_payer(&[memo.clone(), transfer.clone()], Some(&payer.pubkey()));
+    append_tip_to_transaction(&mut tx, &tip).unwrap();
+    tx.sign(&[&payer], Hash::default());
+
+    let mut expected = Transaction::new_with_payer(&[memo, transfer, tip], Some(&payer.pubkey()));
+    expected.sign(&[&payer], Hash::default());
+
+    // 账户顺序可能不同，但解码后的指令应一致
+    let decompile = |tx: &Transaction| {
+        let message = &tx.message;
+        message
+            .instructions
+            .iter()
+            .map(|ix| {
+                let accounts = ix
+                    .accounts
+                    .iter()
+                    .map(|&i| {
+                        let i = i as usize;
+                        (message.account_keys[i], message.is_signer(i), message.is_writable(i))
+                    })
+                    .collect::<Vec<_>>();
+                (message.account_keys[ix.program_id_index as usize], accounts, ix.data.clone())
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(decompile(&tx), decompile(&expected));
+    assert!(tx.verify().is_ok());
+}
+
+#[test]
+fn append_tip_to_v0_message() {
+    let payer = Keypair::new();
+    let transfer = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 5);
+    let message = v0::Message::try_compile(&payer.pubkey(), &[transfer], &[], Hash::default()).unwrap();
+    let mut tx = VersionedTransaction {
+        signatures: vec![Default::default()],
+        message: VersionedMessage::V0(message),
+    };
+
+    let tip = tip_instruction_to(&payer.pubkey(), &mainnet_tip_accounts()[0], 1_000);
+    append_tip_to_versioned_transaction(&mut tx, &tip).unwrap();
+    assert_eq!(tx.message.instructions().len(), 2);
+
+    let tx = VersionedTransaction::try_new(tx.message, &[&payer]).unwrap();
+    assert!(tx.verify_with_results().into_iter().all(|ok| ok));
+}
+
+#[test]
+fn builder_appends_tip_to_last_transaction() {
+    let payer = Keypair::new();
+    let tip_account = mainnet_tip_accounts()[0];
+    let first = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let second = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 2);
+
+    let transactions = BundleBuilder::new(payer.pubkey(), tip_account)
+        .add_transaction(Transaction::new_with_payer(&[first], Some(&payer.pubkey())))
+        .add_transaction(Transaction::new_with_payer(&[second], Some(&payer.pubkey())))
+        .tip_strategy(FixedTip(5_000))
+        .build(&TipContext::default())
+        .unwrap();
+
+    assert_eq!(transactions[0].message.instructions().len(), 1);
+    assert_eq!(transactions[1].message.instructions().len(), 2);
+}
+
+#[test]
+fn validator_reports_distinct_errors() {
+    let payer = Keypair::new();
+    let tip_account = mainnet_tip_accounts()[0];
+    let receiver = Pubkey::new_unique();
+    let transfer = system_instruction::transfer(&payer.pubkey(), &receiver, 1);
+    let tip = tip_instruction_to(&payer.pubkey(), &tip_account, 1_000);
+    let validator = BundleValidator::default();
+
+    let with_tip = signed(&payer, &[transfer.clone(), tip]);
+    let without_tip = signed(&payer, &[transfer]);
+    assert_eq!(validator.validate(&[without_tip.clone(), with_tip.clone()]), Ok(()));
+
+    assert_eq!(validator.validate(&[]), Err(BundleValidationError::EmptyBundle));
+    assert_eq!(
+        validator.validate(&[without_tip]),
+        Err(BundleValidationError::MissingTip)
+    );
+    assert!(matches!(
+        validator.validate(&[with_tip.clone(), with_tip.clone()]),
+        Err(BundleValidationError::DuplicateSignature { index: 1, .. })
+    ));
+
+    let mut unsigned = with_tip.clone();
+    unsigned.signatures[0] = Default::default();
+    assert_eq!(
+        validator.validate(&[unsigned]),
+        Err(BundleValidationError::MissingSignature { index: 0 })
+    );
+
+    let latest = Hash::new_unique();
+    assert!(matches!(
+        BundleValidator::default().latest_blockhash(latest).validate(std::slice::from_ref(&with_tip)),
+        Err(BundleValidationError::StaleBlockhash { .. })
+    ));
+    assert_eq!(
+        BundleValidator::default()
+            .revertible(with_tip.signatures[0])
+            .validate(&[with_tip]),
+        Err(BundleValidationError::TipInRevertibleTransaction { index: 0 })
+    );
+}
+
+#[test]
+fn transaction_without_required_signatures_is_rejected() {
+    // 不需要任何签名的 tip 转账，曾在检查 revertible 时因 signatures[0] 越界而 panic
+    let payer = Pubkey::new_unique();
+    let tip = tip_instruction_to(&payer, &mainnet_tip_accounts()[0], 1_000);
+    let mut message = solana_sdk::message::Message::new(&[tip], Some(&payer));
+    message.header.num_required_signatures = 0;
+    message.header.num_readonly_signed_accounts = 0;
+    let tx = VersionedTransaction {
+        signatures: Vec::new(),
+        message: VersionedMessage::Legacy(message),
+    };
+
+    for validator in [
+        BundleValidator::default(),
+        BundleValidator::default().revertible(Default::default()),
+    ] {
+        assert_eq!(
+            validator.validate(std::slice::from_ref(&tx)),
+            Err(BundleValidationError::MissingSignature { index: 0 })
+        );
+    }
+}
+
+#[test]
+fn v0_transaction_with_lookup_table_gets_tip_and_signs() {
+    let payer = Keypair::new();
+    let receivers = (0..40).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+    let lookup_table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: receivers.clone(),
+    };
+    let instructions = receivers
+        .iter()
+        .map(|receiver| system_instruction::transfer(&payer.pubkey(), receiver, 1))
+        .collect::<Vec<_>>();
+
+    // 40 个账户不使用查找表时超过 packet 大小
+    assert!(unsigned_v0_transaction(&payer.pubkey(), &instructions, &[], Hash::default()).is_err());
+
+    let transactions = BundleBuilder::new(payer.pubkey(), mainnet_tip_accounts()[0])
+        .add_v0_instructions(
+            &payer.pubkey(),
+            &instructions[..20],
+            std::slice::from_ref(&lookup_table),
+            Hash::default(),
+        )
+        .unwrap()
+        .tip_strategy(FixedTip(1_000))
+        .build(&TipContext::default())
+        .unwrap();
+    let tx = VersionedTransaction::try_new(transactions[0].message.clone(), &[&payer]).unwrap();
+    assert_eq!(tx.message.address_table_lookups().unwrap().len(), 1);
+    assert!(BundleValidator::default().validate(&[tx]).is_ok());
+
+    let signed = build_v0_transaction(
+        &payer.pubkey(),
+        &instructions[..5],
+        &[lookup_table],
+        Hash::default(),
+        &[&payer],
+    )
+    .unwrap();
+    assert!(signed.verify_with_results().into_iter().all(|ok| ok));
+}
+
+#[test]
+fn v0_tip_reuses_account_loaded_from_lookup_table() {
+    let payer = Keypair::new();
+    let tip_account = mainnet_tip_accounts()[0];
+    let lookup_table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: vec![Pubkey::new_unique(), tip_account],
+    };
+    let tables = std::slice::from_ref(&lookup_table);
+    let tip = tip_instruction_to(&payer.pubkey(), &tip_account, 1_000);
+
+    // 交易本身已向 tip account 转账，tip account 由查找表以 writable 方式加载
+    let transfer = system_instruction::transfer(&payer.pubkey(), &tip_account, 5);
+    let mut tx = unsigned_v0_transaction(
+        &payer.pubkey(),
+        std::slice::from_ref(&transfer),
+        tables,
+        Hash::default(),
+    )
+    .unwrap();
+    let static_keys = tx.message.static_account_keys().to_vec();
+    assert!(!static_keys.contains(&tip_account));
+
+    // 不提供查找表时无法识别加载的账户，返回错误
+    assert!(append_tip_to_versioned_transaction(&mut tx.clone(), &tip).is_err());
+
+    append_tip_to_v0_transaction(&mut tx, &tip, tables).unwrap();
+    assert_eq!(tx.message.static_account_keys(), static_keys.as_slice());
+    let expected = unsigned_v0_transaction(
+        &payer.pubkey(),
+        &[transfer.clone(), tip.clone()],
+        tables,
+        Hash::default(),
+    )
+    .unwrap();
+    assert_eq!(tx.message, expected.message);
+    let signed = VersionedTransaction::try_new(tx.message, &[&payer]).unwrap();
+    assert!(signed.verify_with_results().into_iter().all(|ok| ok));
+
+    // builder 使用 add_v0_instructions 传入的查找表
+    let transactions = BundleBuilder::new(payer.pubkey(), tip_account)
+        .add_v0_instructions(&payer.pubkey(), &[transfer], tables, Hash::default())
+        .unwrap()
+        .tip_strategy(FixedTip(1_000))
+        .build(&TipContext::default())
+        .unwrap();
+    assert_eq!(transactions[0].message, expected.message);
+
+    // 查找表以只读方式加载的 tip account 不能接收转账
+    let read = Instruction::new_with_bytes(
+        Pubkey::new_unique(),
+        b"read",
+        vec![AccountMeta::new_readonly(tip_account, false)],
+    );
+    let mut tx = unsigned_v0_transaction(&payer.pubkey(), &[read], tables, Hash::default()).unwrap();
+    let err = append_tip_to_v0_transaction(&mut tx, &tip, tables).unwrap_err();
+    assert!(err.to_string().contains("loaded read-only"), "{}", err);
+}
+
+#[test]
+fn append_tip_checks_account_limit_and_indexes() {
+    let payer = Keypair::new();
+    let tip = tip_instruction_to(&payer.pubkey(), &mainnet_tip_accounts()[0], 1_000);
+
+    // 2 个静态账户加上 252 个查找表账户，再加入 system program 和 tip account 会超出 u8 索引范围
+    let lookup_table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: (0..252).map(|_| Pubkey::new_unique()).collect(),
+    };
+    let accounts = lookup_table
+        .addresses
+        .iter()
+        .map(|address| AccountMeta::new_readonly(*address, false))
+        .collect();
+    let ix = Instruction::new_with_bytes(Pubkey::new_unique(), b"many", accounts);
+    let tables = std::slice::from_ref(&lookup_table);
+    let message = v0::Message::try_compile(&payer.pubkey(), &[ix], tables, Hash::default()).unwrap();
+    let mut tx = VersionedTransaction {
+        signatures: vec![Default::default()],
+        message: VersionedMessage::V0(message),
+    };
+    let err = append_tip_to_v0_transaction(&mut tx, &tip, tables).unwrap_err();
+    assert!(err.to_string().contains("Too many accounts"), "{}", err);
+
+    // 越界的账户索引在后移时返回错误而不是溢出
+    let transfer = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let mut tx = Transaction::new_with_payer(&[transfer], Some(&payer.pubkey()));
+    tx.message.instructions[0].accounts.push(u8::MAX);
+    let err = append_tip_to_transaction(&mut tx, &tip).unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{}", err);
+}
+
+#[test]
+fn validator_enforces_minimum_tip_and_placement() {
+    let payer = Keypair::new();
+    let tip_accounts = mainnet_tip_accounts();
+    let transfer = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let small_tip = tip_instruction_to(&payer.pubkey(), &tip_accounts[0], 600);
+    let other_tip = tip_instruction_to(&payer.pubkey(), &tip_accounts[1], 500);
+
+    let first = signed(&payer, &[transfer.clone(), small_tip.clone()]);
+    let last = signed(&payer, &[transfer, other_tip]);
+    let validator = BundleValidator::default();
+    assert_eq!(validator.tip_lamports(&[first.clone(), last.clone()]), 1_100);
+    assert_eq!(
+        validator.validate(std::slice::from_ref(&last)),
+        Err(BundleValidationError::TipTooLow {
+            lamports: 500,
+            minimum: 1_000
+        })
+    );
+    assert_eq!(
+        BundleValidator::default()
+            .min_tip_lamports(500)
+            .validate(std::slice::from_ref(&last)),
+        Ok(())
+    );
+
+    // 默认只警告 tip 不在最后一笔交易
+    assert_eq!(validator.validate(&[first.clone(), last.clone()]), Ok(()));
+    assert_eq!(
+        BundleValidator::default()
+            .tip_placement(TipPlacement::Error)
+            .validate(&[first.clone(), last.clone()]),
+        Err(BundleValidationError::TipNotInLastTransaction { index: 0 })
+    );
+    let no_tip = signed(
+        &payer,
+        &[system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 2)],
+    );
+    assert_eq!(
+        BundleValidator::default()
+            .tip_placement(TipPlacement::Error)
+            .validate(&[no_tip, signed(&payer, &[small_tip.clone(), small_tip])]),
+        Ok(())
+    );
+}
+
+#[test]
+fn dont_front_transaction_must_be_first() {
+    let payer = Keypair::new();
+    let account = dont_front_account();
+    let transfer = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let tip = tip_instruction_to(&payer.pubkey(), &mainnet_tip_accounts()[0], 1_000);
+
+    let mut tx =
+        Transaction::new_with_payer(std::slice::from_ref(&transfer), Some(&payer.pubkey()));
+    protect_transaction(&mut tx, 0, &account).unwrap();
+    assert!(protect_transaction(&mut tx, 1, &account).is_err());
+    tx.sign(&[&payer], Hash::default());
+    let index = tx.message.account_keys.iter().position(|k| *k == account).unwrap();
+    assert!(!tx.message.is_writable(index));
+    assert!(!tx.message.is_signer(index));
+    assert!(tx.message.instructions[0].accounts.contains(&(index as u8)));
+    let protected: VersionedTransaction = tx.into();
+    assert!(is_front_run_protected(&protected));
+
+    let tipped = signed(&payer, &[transfer, tip]);
+    assert!(!is_front_run_protected(&tipped));
+    let validator = BundleValidator::default();
+    assert_eq!(validator.validate(&[protected.clone(), tipped.clone()]), Ok(()));
+    assert_eq!(
+        validator.validate(&[tipped, protected]),
+        Err(BundleValidationError::FrontRunProtectedNotFirst { index: 1 })
+    );
+}
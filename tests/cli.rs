@@ -0,0 +1,282 @@
+use jito_sdk_rust::{JitoJsonRpcSDK, MockBlockEngine, MockBundleOutcome, MockResponse};
+use serde_json::{json, Value};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::process::Output;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+
+// 运行 jito 二进制；HOME 指向空目录，避免读取本机的配置文件
+async fn jito(mock: &MockBlockEngine, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_jito"))
+        .args(args)
+        .env("HOME", std::env::temp_dir().join("jito-cli-test-home"))
+        .env_remove("JITO_CONFIG")
+        .env_remove("JITO_UUID")
+        .env("JITO_BLOCK_ENGINE_URL", mock.json_rpc_url())
+        .env("JITO_GRPC_URL", mock.grpc_url())
+        .env("JITO_OUTPUT", "json")
+        .output()
+        .await
+        .unwrap()
+}
+
+fn stdout_lines(output: &Output) -> Vec<Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+async fn submit_bundle(mock: &MockBlockEngine, tx: &str) -> String {
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let response = sdk.send_bundle(Some(json!([[tx]])), None).await.unwrap();
+    response["result"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn tip_accounts_prints_json() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_tip_accounts(vec!["TipAccount1".to_string(), "TipAccount2".to_string()]);
+
+    let output = jito(&mock, &["tip-accounts"]).await;
+    assert!(output.status.success(), "{:?}", output);
+    let printed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(printed, json!(["TipAccount1", "TipAccount2"]));
+
+    let table = jito(&mock, &["tip-accounts", "--output", "table"]).await;
+    let table = String::from_utf8_lossy(&table.stdout);
+    assert!(table.contains("TIP ACCOUNT"), "{}", table);
+    assert!(table.contains("TipAccount2"), "{}", table);
+}
+
+#[tokio::test]
+async fn send_bundle_reads_transactions_from_files() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let payer = Keypair::new();
+    let tx: VersionedTransaction = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &payer.pubkey(),
+            &Pubkey::new_unique(),
+            1,
+        )],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::default(),
+    )
+    .into();
+    let path = std::env::temp_dir().join(format!("jito-cli-test-{}.bin", payer.pubkey()));
+    std::fs::write(&path, bincode::serialize(&tx).unwrap()).unwrap();
+
+    let output = jito(&mock, &["send-bundle", path.to_str().unwrap()]).await;
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let printed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json!(mock.bundle_ids()), json!([printed["bundle_id"]]));
+    let request = mock.requests().pop().unwrap();
+    assert_eq!(request.method, "sendBundle");
+    assert_eq!(request.body["params"][1]["encoding"], "base64");
+}
+
+#[tokio::test]
+async fn config_file_and_errors() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let path = std::env::temp_dir().join(format!("jito-cli-test-{}.toml", Pubkey::new_unique()));
+    std::fs::write(
+        &path,
+        format!("block_engine_url = \"{}\"\n", mock.json_rpc_url()),
+    )
+    .unwrap();
+
+    // 没有命令行参数和环境变量时使用配置文件中的地址
+    let output = Command::new(env!("CARGO_BIN_EXE_jito"))
+        .args(["tip-accounts", "--output", "json", "--config"])
+        .arg(&path)
+        .env_remove("JITO_BLOCK_ENGINE_URL")
+        .output()
+        .await
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(mock.requests().len(), 1);
+
+    let output = jito(&mock, &["send-bundle", "not base64!"]).await;
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("neither a file nor base64"));
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn watch_exits_zero_when_finalized() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_landing_delay(Duration::from_millis(200));
+    let bundle_id = submit_bundle(&mock, "a").await;
+
+    let output = jito(
+        &mock,
+        &[
+            "watch",
+            &bundle_id,
+            "--interval-ms",
+            "50",
+            "--timeout-secs",
+            "10",
+        ],
+    )
+    .await;
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    let transitions = stdout_lines(&output);
+    assert_eq!(transitions.first().unwrap()["to"], "pending");
+    let last = transitions.last().unwrap();
+    assert_eq!(last["bundle_id"], bundle_id.as_str());
+    assert_eq!(last["to"], "finalized");
+    assert!(last["slot"].as_u64().is_some());
+}
+
+#[tokio::test]
+async fn watch_exits_two_when_a_bundle_fails() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let landed = submit_bundle(&mock, "a").await;
+    mock.set_bundle_outcome(MockBundleOutcome::Fail);
+    let failed = submit_bundle(&mock, "b").await;
+
+    let output = jito(
+        &mock,
+        &[
+            "watch",
+            &landed,
+            &failed,
+            "--interval-ms",
+            "50",
+            "--timeout-secs",
+            "10",
+        ],
+    )
+    .await;
+    assert_eq!(output.status.code(), Some(2), "{:?}", output);
+    let states = stdout_lines(&output)
+        .into_iter()
+        .map(|t| {
+            (
+                t["bundle_id"].as_str().unwrap().to_string(),
+                t["to"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert!(states.contains(&(landed, json!("finalized"))));
+    assert!(states.contains(&(failed, json!("failed"))));
+}
+
+#[tokio::test]
+async fn watch_exits_three_for_unknown_bundle_without_waiting() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let started = Instant::now();
+    let output = jito(
+        &mock,
+        &[
+            "watch",
+            "no-such-bundle",
+            "--interval-ms",
+            "50",
+            "--timeout-secs",
+            "30",
+        ],
+    )
+    .await;
+    assert_eq!(output.status.code(), Some(3), "{:?}", output);
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert_eq!(stdout_lines(&output)[0]["to"], "invalid");
+}
+
+#[tokio::test]
+async fn watch_keeps_polling_after_transient_errors() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let bundle_id = submit_bundle(&mock, "a").await;
+    mock.push_response(MockResponse::RateLimited);
+    mock.push_response(MockResponse::Reject {
+        code: -32603,
+        message: "internal error".to_string(),
+    });
+
+    let output = jito(
+        &mock,
+        &[
+            "watch",
+            &bundle_id,
+            "--interval-ms",
+            "50",
+            "--timeout-secs",
+            "10",
+        ],
+    )
+    .await;
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(
+        stderr.matches("Failed to poll bundle statuses").count(),
+        2,
+        "{}",
+        stderr
+    );
+    assert_eq!(stdout_lines(&output).last().unwrap()["to"], "finalized");
+}
+
+#[tokio::test]
+async fn watch_exits_four_when_polling_keeps_failing() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let bundle_id = submit_bundle(&mock, "a").await;
+    for _ in 0..100 {
+        mock.push_response(MockResponse::RateLimited);
+    }
+
+    let output = jito(
+        &mock,
+        &[
+            "watch",
+            &bundle_id,
+            "--interval-ms",
+            "50",
+            "--timeout-secs",
+            "1",
+        ],
+    )
+    .await;
+    assert_eq!(output.status.code(), Some(4), "{:?}", output);
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Failed to poll bundle statuses"));
+}
+
+#[tokio::test]
+async fn watch_exits_four_on_timeout_and_reads_ids_from_stdin() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_landing_delay(Duration::from_secs(60));
+    let bundle_id = submit_bundle(&mock, "a").await;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_jito"))
+        .args(["watch", "--interval-ms", "50", "--timeout-secs", "1"])
+        .env("HOME", std::env::temp_dir().join("jito-cli-test-home"))
+        .env("JITO_BLOCK_ENGINE_URL", mock.json_rpc_url())
+        .env("JITO_OUTPUT", "json")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    {
+        use tokio::io::AsyncWriteExt;
+        let mut stdin = child.stdin.take().unwrap();
+        stdin
+            .write_all(format!("{}\n", bundle_id).as_bytes())
+            .await
+            .unwrap();
+    }
+    let output = child.wait_with_output().await.unwrap();
+    assert_eq!(output.status.code(), Some(4), "{:?}", output);
+    let transitions = stdout_lines(&output);
+    assert_eq!(transitions.len(), 1);
+    assert_eq!(transitions[0]["to"], "pending");
+}
@@ -0,0 +1,163 @@
+use jito_sdk_rust::scheduler::BLOCKHASH_VALID_SLOTS;
+use jito_sdk_rust::{
+    BundleScheduler, GrpcClient, JitoJsonRpcSDK, MockBlockEngine, MockResponse, ScheduleEvent,
+};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+fn transfer_tx(payer: &Keypair, lamports: u64) -> VersionedTransaction {
+    let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports);
+    Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], Hash::default())
+        .into()
+}
+
+#[tokio::test]
+async fn get_tip_accounts_round_trip() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_tip_accounts(vec!["96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5".to_string()]);
+    let client = GrpcClient::connect(&mock.grpc_url()).await.unwrap();
+
+    let response = client.get_tip_accounts().await.unwrap();
+    assert_eq!(
+        response.accounts,
+        vec!["96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5".to_string()]
+    );
+    assert_eq!(mock.requests()[0].method, "GetTipAccounts");
+}
+
+#[tokio::test]
+async fn send_bundle_round_trip() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let client = GrpcClient::connect(&mock.grpc_url()).await.unwrap();
+    let payer = Keypair::new();
+
+    let uuid = client
+        .send_bundle(vec![transfer_tx(&payer, 1), transfer_tx(&payer, 2)])
+        .await
+        .unwrap();
+    assert_eq!(mock.bundle_ids(), vec![uuid]);
+
+    let request = &mock.requests()[0];
+    assert_eq!(request.path, "/searcher.SearcherService/SendBundle");
+    assert_eq!(request.body["packets"], 2);
+}
+
+#[tokio::test]
+async fn send_bundle_propagates_rejection() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let client = GrpcClient::connect(&mock.grpc_url()).await.unwrap();
+    mock.push_response(MockResponse::Reject {
+        code: 0,
+        message: "bundle simulation failed".to_string(),
+    });
+
+    let err = client
+        .send_bundle(vec![transfer_tx(&Keypair::new(), 1)])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("bundle simulation failed"));
+}
+
+#[tokio::test]
+async fn connect_fails_for_unreachable_endpoint() {
+    assert!(GrpcClient::connect("http://127.0.0.1:1").await.is_err());
+}
+
+#[tokio::test]
+async fn connect_rejects_empty_and_invalid_urls() {
+    for url in ["", "not a url", "https://bad host"] {
+        let err = GrpcClient::connect(url).await.unwrap_err().to_string();
+        assert!(
+            err.contains("gRPC url is empty") || err.contains("Invalid url"),
+            "{}: {}",
+            url,
+            err
+        );
+    }
+}
+
+#[tokio::test]
+async fn sdk_tip_accounts_over_grpc_needs_a_url() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let err = sdk.get_tip_accounts_grpc().await.unwrap_err().to_string();
+    assert!(err.contains("gRPC url is not set"), "{}", err);
+
+    let sdk = sdk.with_grpc_url(&mock.grpc_url());
+    let response = sdk.get_tip_accounts_grpc().await.unwrap();
+    assert_eq!(response["result"].as_array().unwrap().len(), 8);
+}
+
+#[tokio::test]
+async fn scheduler_waits_for_jito_leader() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_next_leader(50, "Leader111");
+    let client = GrpcClient::connect(&mock.grpc_url()).await.unwrap();
+    let mut scheduler = BundleScheduler::new(client).with_lead_slots(2);
+    let deadline = mock.current_slot() + BLOCKHASH_VALID_SLOTS;
+    let id = scheduler.schedule(vec![transfer_tx(&Keypair::new(), 1)], deadline);
+
+    assert!(scheduler.tick().await.unwrap().is_empty());
+    assert_eq!(scheduler.pending().len(), 1);
+    assert!(mock.bundle_ids().is_empty());
+
+    mock.set_next_leader(1, "Leader111");
+    let events = scheduler.tick().await.unwrap();
+    match &events[..] {
+        [ScheduleEvent::Sent {
+            id: sent,
+            region,
+            bundle_id,
+            ..
+        }] => {
+            assert_eq!(*sent, id);
+            assert_eq!(region, "mock");
+            assert_eq!(mock.bundle_ids(), vec![bundle_id.clone()]);
+        }
+        other => panic!("unexpected events: {:?}", other),
+    }
+    assert!(scheduler.is_empty());
+}
+
+#[tokio::test]
+async fn scheduler_drops_bundle_expiring_before_leader() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_next_leader(50, "Leader111");
+    let client = GrpcClient::connect(&mock.grpc_url()).await.unwrap();
+    let mut scheduler = BundleScheduler::new(client);
+    let id = scheduler.schedule(
+        vec![transfer_tx(&Keypair::new(), 1)],
+        mock.current_slot() + 10,
+    );
+
+    let events = scheduler.tick().await.unwrap();
+    assert!(matches!(events[..], [ScheduleEvent::Dropped { id: dropped, .. }] if dropped == id));
+    assert!(scheduler.is_empty());
+    assert!(mock.bundle_ids().is_empty());
+}
+
+#[tokio::test]
+async fn scheduler_routes_to_leader_region() {
+    let leader_info = MockBlockEngine::start().await.unwrap();
+    leader_info.set_regions(vec!["ny".to_string()]);
+    leader_info.set_next_leader(0, "Leader111");
+    let ny = MockBlockEngine::start().await.unwrap();
+
+    let mut scheduler =
+        BundleScheduler::new(GrpcClient::connect(&leader_info.grpc_url()).await.unwrap())
+            .with_region("ny", GrpcClient::connect(&ny.grpc_url()).await.unwrap());
+    scheduler.schedule(
+        vec![transfer_tx(&Keypair::new(), 1)],
+        leader_info.current_slot() + BLOCKHASH_VALID_SLOTS,
+    );
+
+    let events = scheduler.tick().await.unwrap();
+    assert!(matches!(&events[..], [ScheduleEvent::Sent { region, .. }] if region == "ny"));
+    assert_eq!(ny.bundle_ids().len(), 1);
+    assert!(leader_info.bundle_ids().is_empty());
+}
@@ -0,0 +1,416 @@
+use jito_sdk_rust::{
+    BundleState, BundleTracker, Encoding, InflightBundleStatus, JitoJsonRpcSDK, JsonRpcTransport,
+    MockBlockEngine, MockResponse, TransportFuture, TransportResponse,
+};
+use serde_json::{json, Value};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn get_tip_accounts_sends_expected_request() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let response = sdk.get_tip_accounts().await.unwrap();
+    assert_eq!(response["result"].as_array().unwrap().len(), 8);
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/api/v1/bundles");
+    assert_eq!(requests[0].query, None);
+    assert_eq!(requests[0].method, "getTipAccounts");
+    assert_eq!(requests[0].body["jsonrpc"], "2.0");
+    assert_eq!(requests[0].body["params"], json!([]));
+}
+
+#[tokio::test]
+async fn uuid_is_sent_as_query_string() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), Some("api-key".to_string()));
+
+    sdk.get_tip_accounts().await.unwrap();
+    sdk.get_bundle_statuses(vec!["id".to_string()]).await.unwrap();
+    sdk.send_bundle(Some(json!([["tx"]])), Some("bundle-key"))
+        .await
+        .unwrap();
+
+    let queries = mock
+        .requests()
+        .into_iter()
+        .map(|r| r.query)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        queries,
+        vec![
+            Some("uuid=api-key".to_string()),
+            Some("uuid=api-key".to_string()),
+            Some("uuid=bundle-key".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn get_random_tip_account_picks_from_tip_accounts() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let tip_accounts = vec![
+        "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5".to_string(),
+        "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe".to_string(),
+    ];
+    mock.set_tip_accounts(tip_accounts.clone());
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let account = sdk.get_random_tip_account().await.unwrap();
+    assert!(tip_accounts.contains(&account));
+
+    let pubkey = sdk.random_tip_pubkey().await.unwrap();
+    assert!(tip_accounts.contains(&pubkey.to_string()));
+}
+
+#[tokio::test]
+async fn get_random_tip_account_fails_without_accounts() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_tip_accounts(vec![]);
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let err = sdk.get_random_tip_account().await.unwrap_err();
+    assert!(err.to_string().contains("No tip accounts available"));
+}
+
+#[tokio::test]
+async fn send_bundle_sends_params_and_returns_bundle_id() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let params = json!([["tx1", "tx2"]]);
+    let response = sdk.send_bundle(Some(params.clone()), None).await.unwrap();
+    let bundle_id = response["result"].as_str().unwrap();
+    assert_eq!(mock.bundle_ids(), vec![bundle_id.to_string()]);
+
+    let request = &mock.requests()[0];
+    assert_eq!(request.path, "/api/v1/bundles");
+    assert_eq!(request.method, "sendBundle");
+    assert_eq!(
+        request.body["params"],
+        json!([["tx1", "tx2"], { "encoding": "base64" }])
+    );
+}
+
+#[tokio::test]
+async fn encoding_option_is_added_unless_specified() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None).with_encoding(Encoding::Base58);
+
+    sdk.send_bundle(Some(json!([["tx"], { "foo": 1 }])), None)
+        .await
+        .unwrap();
+    sdk.send_bundle(Some(json!([["tx"], { "encoding": "base64" }])), None)
+        .await
+        .unwrap();
+
+    let params = mock
+        .requests()
+        .into_iter()
+        .map(|r| r.body["params"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(params[0], json!([["tx"], { "foo": 1, "encoding": "base58" }]));
+    assert_eq!(params[1], json!([["tx"], { "encoding": "base64" }]));
+}
+
+#[tokio::test]
+async fn send_transaction_encodes_and_returns_signature() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let payer = Keypair::new();
+    let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let tx: VersionedTransaction =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], Hash::default())
+            .into();
+
+    for encoding in [Encoding::Base64, Encoding::Base58] {
+        let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None).with_encoding(encoding);
+        let response = sdk.send_transaction(&tx, true).await.unwrap();
+        assert_eq!(response["result"], tx.signatures[0].to_string());
+
+        let request = mock.requests().pop().unwrap();
+        assert_eq!(request.path, "/api/v1/transactions");
+        assert_eq!(request.query.as_deref(), Some("bundleOnly=true"));
+        assert_eq!(request.body["params"][0], encoding.encode_transaction(&tx).unwrap());
+        assert_eq!(request.body["params"][1]["encoding"], encoding.as_str());
+    }
+}
+
+#[tokio::test]
+async fn send_bundle_validates_params_before_sending() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let invalid = [
+        None,
+        Some(json!([])),
+        Some(json!(["tx"])),
+        Some(json!([[]])),
+        Some(json!([["1", "2", "3", "4", "5", "6"]])),
+    ];
+    for params in invalid {
+        assert!(sdk.send_bundle(params, None).await.is_err());
+    }
+    assert!(mock.requests().is_empty());
+}
+
+#[tokio::test]
+async fn send_bundle_propagates_json_rpc_error() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    mock.push_response(MockResponse::Reject {
+        code: -32602,
+        message: "bundle contains an already processed transaction".to_string(),
+    });
+
+    let err = sdk
+        .send_bundle(Some(json!([["tx"]])), None)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("bundle contains an already processed transaction"));
+}
+
+#[tokio::test]
+async fn send_bundle_propagates_rate_limit() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    mock.push_response(MockResponse::RateLimited);
+
+    let err = sdk
+        .send_bundle(Some(json!([["tx"]])), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Rate limit exceeded"));
+
+    // 脚本化响应只作用于一次请求
+    assert!(sdk.send_bundle(Some(json!([["tx"]])), None).await.is_ok());
+}
+
+#[tokio::test]
+async fn get_bundle_statuses_reports_landing() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_landing_delay(Duration::from_millis(200));
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let response = sdk.send_bundle(Some(json!([["tx"]])), None).await.unwrap();
+    let bundle_id = response["result"].as_str().unwrap().to_string();
+
+    let pending = sdk.get_bundle_statuses(vec![bundle_id.clone()]).await.unwrap();
+    assert_eq!(pending["result"]["value"][0], Value::Null);
+
+    let request = mock.requests().pop().unwrap();
+    assert_eq!(request.method, "getBundleStatuses");
+    assert_eq!(request.body["params"], json!([[bundle_id.clone()]]));
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let landed = sdk.get_bundle_statuses(vec![bundle_id.clone()]).await.unwrap();
+    let status = &landed["result"]["value"][0];
+    assert_eq!(status["bundle_id"], bundle_id);
+    assert_eq!(status["confirmation_status"], "finalized");
+    assert!(status["slot"].as_u64().is_some());
+}
+
+#[tokio::test]
+async fn tracker_states_never_move_backwards() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_finalization_delay(Duration::from_secs(60));
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let response = sdk.send_bundle(Some(json!([["tx"]])), None).await.unwrap();
+    let bundle_id = response["result"].as_str().unwrap();
+
+    let mut tracker = BundleTracker::new();
+    tracker.track(bundle_id, 0);
+    let transitions = tracker.poll(&sdk).await.unwrap();
+    assert_eq!(transitions.len(), 1);
+    assert_eq!(transitions[0].from, BundleState::Unknown);
+    assert_eq!(transitions[0].to, BundleState::Confirmed);
+
+    // 超出查询窗口后 Jito 报告 Invalid，已确认的 bundle 保持 Confirmed
+    mock.forget_bundles();
+    assert!(tracker.poll(&sdk).await.unwrap().is_empty());
+    let bundle = tracker.get(bundle_id).unwrap();
+    assert_eq!(bundle.state, BundleState::Confirmed);
+    assert!(bundle.slot.is_some());
+}
+
+#[tokio::test]
+async fn get_bundle_statuses_returns_error_object() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    mock.push_response(MockResponse::Reject {
+        code: -32602,
+        message: "invalid bundle id".to_string(),
+    });
+
+    let response = sdk
+        .get_bundle_statuses(vec!["id".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(response["error"]["message"], "invalid bundle id");
+}
+
+#[tokio::test]
+async fn requests_use_unique_ids() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let first = sdk.get_tip_accounts().await.unwrap();
+    let second = sdk.get_tip_accounts().await.unwrap();
+
+    let ids = mock
+        .requests()
+        .iter()
+        .map(|request| request.body["id"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![first["id"].clone(), second["id"].clone()]);
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[tokio::test]
+async fn mismatched_response_id_is_an_error() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    mock.push_response(MockResponse::MismatchedId);
+
+    let err = sdk.get_tip_accounts().await.unwrap_err().to_string();
+    let id = &mock.requests()[0].body["id"];
+    assert!(err.contains("response id mismatch"), "{}", err);
+    assert!(err.contains(&format!("(id {})", id)), "{}", err);
+}
+
+#[tokio::test]
+async fn batch_sends_one_request_and_demultiplexes_results() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let bundle_id = sdk.send_bundle(Some(json!([["tx"]])), None).await.unwrap()["result"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let mut batch = sdk.batch();
+    let tips = batch.get_tip_accounts();
+    let statuses = batch.get_bundle_statuses(vec![bundle_id.clone()]);
+    let inflight = batch.get_in_flight_bundle_statuses(vec![bundle_id.clone()]);
+    let mut results = batch.send().await.unwrap();
+
+    assert!(results.was_batched());
+    assert_eq!(mock.requests().len(), 2);
+    assert_eq!(mock.requests()[1].method, "batch");
+    assert_eq!(results.take(tips).unwrap()["result"].as_array().unwrap().len(), 8);
+    assert_eq!(results.take(statuses).unwrap()["result"]["value"][0]["bundle_id"], bundle_id);
+    assert_eq!(results.take(inflight).unwrap()["result"]["value"][0]["bundle_id"], bundle_id);
+    assert!(results.take(tips).is_err());
+}
+
+#[tokio::test]
+async fn batch_results_take_typed_values() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let bundle_id = sdk.send_bundle(Some(json!([["tx"]])), None).await.unwrap()["result"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let ids = vec![bundle_id.clone(), "unknown".to_string()];
+
+    let mut batch = sdk.batch();
+    let tips = batch.get_tip_accounts();
+    let statuses = batch.get_bundle_statuses(ids.clone());
+    let inflight = batch.get_in_flight_bundle_statuses(ids);
+    let missing = batch.call("noSuchMethod", json!([]));
+    let mut results = batch.send().await.unwrap();
+
+    let tips = results.take_tip_accounts(tips).unwrap();
+    assert_eq!(tips.len(), 8);
+
+    let statuses = results.take_bundle_statuses(statuses).unwrap();
+    assert_eq!(statuses.len(), 2);
+    let landed = statuses[0].as_ref().unwrap();
+    assert_eq!(landed.bundle_id, bundle_id);
+    assert_eq!(landed.transactions, vec!["tx".to_string()]);
+    assert_eq!(landed.err, json!({ "Ok": null }));
+    assert!(landed.confirmation_status.is_some());
+    assert!(statuses[1].is_none());
+
+    let inflight = results.take_in_flight_bundle_statuses(inflight).unwrap();
+    assert_eq!(
+        inflight,
+        vec![
+            InflightBundleStatus {
+                bundle_id: bundle_id.clone(),
+                status: "Landed".to_string(),
+                landed_slot: Some(landed.slot),
+            },
+            InflightBundleStatus {
+                bundle_id: "unknown".to_string(),
+                status: "Invalid".to_string(),
+                landed_slot: None,
+            },
+        ]
+    );
+
+    // JSON-RPC 错误和已取出的调用都返回 Err
+    let err = results.take_result::<Value>(missing).unwrap_err().to_string();
+    assert!(err.contains("Method not found"), "{}", err);
+    assert!(results.take_tip_accounts(missing).is_err());
+}
+
+#[tokio::test]
+async fn batch_falls_back_to_sequential_requests() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_batch_supported(false);
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+
+    let mut batch = sdk.batch();
+    let tips = batch.get_tip_accounts();
+    let inflight = batch.get_in_flight_bundle_statuses(vec!["unknown".to_string()]);
+    let mut results = batch.send().await.unwrap();
+
+    assert!(!results.was_batched());
+    let methods = mock.requests().into_iter().map(|r| r.method).collect::<Vec<_>>();
+    assert_eq!(methods, vec!["batch", "getTipAccounts", "getInflightBundleStatuses"]);
+    assert!(results.take(tips).unwrap()["result"].is_array());
+    assert!(results.take(inflight).unwrap()["result"]["value"].is_array());
+}
+
+// 内存传输层：记录请求，按请求 id 返回固定的 tip accounts
+#[derive(Default)]
+struct InMemoryTransport {
+    requests: Mutex<Vec<(String, Value)>>,
+}
+
+impl JsonRpcTransport for InMemoryTransport {
+    fn post<'a>(&'a self, url: &'a str, body: &'a Value) -> TransportFuture<'a> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((url.to_string(), body.clone()));
+        let response = json!({ "jsonrpc": "2.0", "id": body["id"], "result": ["tip"] });
+        Box::pin(async move { Ok(TransportResponse::new(200, response)) })
+    }
+}
+
+#[tokio::test]
+async fn requests_go_through_custom_transport() {
+    let transport = Arc::new(InMemoryTransport::default());
+    let sdk = JitoJsonRpcSDK::new("memory://block-engine/api/v1", Some("uuid".to_string()))
+        .with_transport(transport.clone());
+
+    assert_eq!(sdk.get_random_tip_account().await.unwrap(), "tip");
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].0, "memory://block-engine/api/v1/bundles?uuid=uuid");
+    assert_eq!(requests[0].1["method"], "getTipAccounts");
+}
@@ -0,0 +1,114 @@
+use jito_sdk_rust::metrics::{
+    BUNDLES_TRACKED_TOTAL, BUNDLE_LANDING_SECONDS, BUNDLE_RESULTS_TOTAL, REQUESTS_TOTAL,
+    REQUEST_DURATION_SECONDS, TIP_LAMPORTS_TOTAL,
+};
+use jito_sdk_rust::{
+    BundleState, BundleTracker, GrpcClient, InMemoryMetrics, JitoJsonRpcSDK, MockBlockEngine,
+    MockBundleOutcome, MockResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn json_rpc_requests_are_counted_by_outcome() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let metrics = Arc::new(InMemoryMetrics::new());
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None).with_metrics(metrics.clone());
+
+    mock.push_response(MockResponse::RateLimited);
+    mock.push_response(MockResponse::Reject {
+        code: -32602,
+        message: "invalid bundle".to_string(),
+    });
+    assert!(sdk.send_bundle(Some(json!([["tx"]])), None).await.is_err());
+    assert!(sdk.send_bundle(Some(json!([["tx"]])), None).await.is_err());
+    assert!(sdk.send_bundle(Some(json!([["tx"]])), None).await.is_ok());
+
+    let count = |outcome| {
+        metrics.counter(
+            REQUESTS_TOTAL,
+            &[
+                ("transport", "json_rpc"),
+                ("method", "sendBundle"),
+                ("outcome", outcome),
+            ],
+        )
+    };
+    assert_eq!(count("rate_limited"), 1);
+    assert_eq!(count("rpc_error"), 1);
+    assert_eq!(count("ok"), 1);
+    assert_eq!(
+        metrics
+            .histogram(
+                REQUEST_DURATION_SECONDS,
+                &[("transport", "json_rpc"), ("method", "sendBundle")]
+            )
+            .len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn grpc_requests_are_counted_by_outcome() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let metrics = Arc::new(InMemoryMetrics::new());
+    let client = GrpcClient::connect(&mock.grpc_url())
+        .await
+        .unwrap()
+        .with_metrics(metrics.clone());
+
+    mock.push_response(MockResponse::RateLimited);
+    assert!(client.get_tip_accounts().await.is_err());
+    assert!(client.get_tip_accounts().await.is_ok());
+
+    let labels = |outcome| {
+        [
+            ("transport", "grpc"),
+            ("method", "GetTipAccounts"),
+            ("outcome", outcome),
+        ]
+    };
+    assert_eq!(metrics.counter(REQUESTS_TOTAL, &labels("rate_limited")), 1);
+    assert_eq!(metrics.counter(REQUESTS_TOTAL, &labels("ok")), 1);
+}
+
+#[tokio::test]
+async fn tracker_records_landing_and_tip_spend() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let metrics = Arc::new(InMemoryMetrics::new());
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let mut tracker = BundleTracker::new().with_metrics(metrics.clone());
+
+    let landed = sdk.send_bundle(Some(json!([["a"]])), None).await.unwrap();
+    mock.set_bundle_outcome(MockBundleOutcome::Fail);
+    let failed = sdk.send_bundle(Some(json!([["b"]])), None).await.unwrap();
+    tracker.track(landed["result"].as_str().unwrap(), 10_000);
+    tracker.track(failed["result"].as_str().unwrap(), 20_000);
+
+    for _ in 0..20 {
+        tracker.poll(&sdk).await.unwrap();
+        if tracker.is_done() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let states = tracker
+        .bundles()
+        .iter()
+        .map(|b| b.state)
+        .collect::<Vec<_>>();
+    assert_eq!(states, vec![BundleState::Finalized, BundleState::Failed]);
+    assert_eq!(metrics.counter(BUNDLES_TRACKED_TOTAL, &[]), 2);
+    assert_eq!(
+        metrics.counter(BUNDLE_RESULTS_TOTAL, &[("state", "finalized")]),
+        1
+    );
+    assert_eq!(
+        metrics.counter(BUNDLE_RESULTS_TOTAL, &[("state", "failed")]),
+        1
+    );
+    assert_eq!(metrics.counter(TIP_LAMPORTS_TOTAL, &[]), 10_000);
+    assert_eq!(metrics.histogram(BUNDLE_LANDING_SECONDS, &[]).len(), 1);
+}
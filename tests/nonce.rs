@@ -0,0 +1,104 @@
+use jito_sdk_rust::nonce::{advance_nonce_index, uses_durable_nonce};
+use jito_sdk_rust::validation::mainnet_tip_accounts;
+use jito_sdk_rust::{
+    BlockhashManager, BundleBuilder, BundleValidationError, BundleValidator, FixedTip,
+    MockBlockEngine, NonceClient, TipContext,
+};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+
+#[tokio::test]
+async fn fetches_nonce_account_state() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let nonce_account = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let nonce = mock.set_nonce_account(nonce_account, authority);
+
+    let info = NonceClient::new(&mock.solana_rpc_url())
+        .get_nonce(&nonce_account)
+        .await
+        .unwrap();
+    assert_eq!(info.nonce_account, nonce_account);
+    assert_eq!(info.authority, authority);
+    assert_eq!(info.nonce, nonce);
+    assert_eq!(info.lamports_per_signature, 5_000);
+
+    let missing = Pubkey::new_unique();
+    let err = NonceClient::new(&mock.solana_rpc_url())
+        .get_nonce(&missing)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("Nonce account {} not found", missing)
+    );
+}
+
+#[tokio::test]
+async fn builds_signed_bundle_with_durable_nonce() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let payer = Keypair::new();
+    let nonce_account = Pubkey::new_unique();
+    mock.set_nonce_account(nonce_account, payer.pubkey());
+    let nonce = NonceClient::new(&mock.solana_rpc_url())
+        .get_nonce(&nonce_account)
+        .await
+        .unwrap();
+    let latest = BlockhashManager::new(&mock.solana_rpc_url())
+        .refresh()
+        .await
+        .unwrap();
+
+    let transfer = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+    let transactions = BundleBuilder::new(payer.pubkey(), mainnet_tip_accounts()[0])
+        .add_nonced_instructions(&payer.pubkey(), &[transfer], &[], &nonce)
+        .unwrap()
+        .tip_strategy(FixedTip(1_000))
+        .blockhash(latest)
+        .signer(payer)
+        .build_signed(&TipContext::default())
+        .await
+        .unwrap();
+
+    // blockhash 不会覆盖 nonce，tip 追加在 advance nonce 之后
+    let tx = &transactions[0];
+    assert_eq!(*tx.message.recent_blockhash(), nonce.nonce);
+    assert!(uses_durable_nonce(tx));
+    assert_eq!(tx.message.instructions().len(), 3);
+    assert_eq!(BundleValidator::default().validate(&transactions), Ok(()));
+    assert_eq!(
+        BundleValidator::default()
+            .latest_blockhash(latest.blockhash)
+            .validate(&transactions),
+        Ok(())
+    );
+}
+
+#[test]
+fn validator_rejects_misplaced_advance_nonce() {
+    let payer = Keypair::new();
+    let advance = system_instruction::advance_nonce_account(&Pubkey::new_unique(), &payer.pubkey());
+    let tip = system_instruction::transfer(&payer.pubkey(), &mainnet_tip_accounts()[0], 1_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[tip, advance],
+        Some(&payer.pubkey()),
+        &[&payer],
+        Hash::new_unique(),
+    )
+    .into();
+
+    assert_eq!(advance_nonce_index(&tx), Some(1));
+    assert!(!uses_durable_nonce(&tx));
+    assert_eq!(
+        BundleValidator::default().validate(&[tx]),
+        Err(BundleValidationError::MisplacedAdvanceNonce {
+            index: 0,
+            instruction_index: 1,
+        })
+    );
+}
@@ -0,0 +1,228 @@
+use jito_sdk_rust::{
+    BlockhashManager, BundleState, Encoding, JitoJsonRpcSDK, MockBlockEngine, MockBundleOutcome,
+    SubmitOptions, SubmitOutcome,
+};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn transfer_tx(payer: &Keypair, lamports: u64) -> VersionedTransaction {
+    let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports);
+    Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], Hash::default())
+        .into()
+}
+
+fn send_bundle_count(mock: &MockBlockEngine) -> usize {
+    mock.requests()
+        .iter()
+        .filter(|r| r.method == "sendBundle")
+        .count()
+}
+
+#[tokio::test]
+async fn resubmits_until_landed() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_landing_delay(Duration::from_millis(250));
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let options = SubmitOptions::new().with_interval(Duration::from_millis(50));
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert!(report.is_landed());
+    match &report.outcome {
+        SubmitOutcome::Landed { bundle_id, slot } => {
+            assert_eq!(mock.bundle_ids(), vec![bundle_id.clone()]);
+            assert!(slot.is_some());
+        }
+        other => panic!("unexpected outcome: {:?}", other),
+    }
+    assert!(report.attempts.len() > 1);
+    assert_eq!(report.attempts.len(), send_bundle_count(&mock));
+    assert!(report.attempts.iter().all(|a| a.result.is_ok()));
+    assert!(report.transitions.iter().any(|t| t.to.is_landed()));
+}
+
+#[tokio::test]
+async fn resubmits_to_every_region() {
+    let primary = MockBlockEngine::start().await.unwrap();
+    let secondary = MockBlockEngine::start().await.unwrap();
+    primary.set_landing_delay(Duration::from_millis(100));
+    let sdk = JitoJsonRpcSDK::new(&primary.json_rpc_url(), None);
+    let options = SubmitOptions::new()
+        .with_interval(Duration::from_millis(50))
+        .with_region(
+            JitoJsonRpcSDK::new(&secondary.json_rpc_url(), None).with_encoding(Encoding::Base58),
+        );
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert!(report.is_landed());
+    let rounds = report.attempts.iter().map(|a| a.round).max().unwrap() + 1;
+    assert_eq!(report.attempts.len(), 2 * rounds as usize);
+    assert_eq!(send_bundle_count(&primary), rounds as usize);
+    assert_eq!(send_bundle_count(&secondary), rounds as usize);
+    assert!(report
+        .attempts
+        .iter()
+        .any(|a| a.region == secondary.json_rpc_url()));
+}
+
+#[tokio::test]
+async fn gives_up_after_timeout() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_landing_delay(Duration::from_secs(60));
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let options = SubmitOptions::new()
+        .with_interval(Duration::from_millis(50))
+        .with_timeout(Duration::from_millis(200));
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert_eq!(report.outcome, SubmitOutcome::TimedOut);
+    assert!(report.attempts.len() >= 2);
+    assert!(report.elapsed >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn stops_when_blockhash_expires() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_landing_delay(Duration::from_secs(60));
+    mock.set_slot_duration(Duration::from_millis(10));
+    let blockhash = Arc::new(
+        BlockhashManager::new(&mock.solana_rpc_url())
+            .with_refresh_interval(Duration::from_millis(20))
+            .start()
+            .await
+            .unwrap(),
+    );
+    let last_valid_block_height = blockhash.block_height().unwrap() + 5;
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let options = SubmitOptions::new()
+        .with_interval(Duration::from_millis(30))
+        .with_expiry(blockhash.clone(), last_valid_block_height);
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert_eq!(report.outcome, SubmitOutcome::Expired);
+    assert!(!report.attempts.is_empty());
+    assert!(report
+        .transitions
+        .iter()
+        .any(|t| t.to == BundleState::Expired));
+}
+
+#[tokio::test]
+async fn records_failed_attempts() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.push_response(jito_sdk_rust::MockResponse::RateLimited);
+    mock.set_landing_delay(Duration::from_millis(100));
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let options = SubmitOptions::new().with_interval(Duration::from_millis(50));
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert!(report.is_landed());
+    assert!(report.attempts[0].result.is_err());
+    assert!(report.attempts[1].result.is_ok());
+}
+
+#[tokio::test]
+async fn stops_on_failure_when_asked() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_bundle_outcome(MockBundleOutcome::Fail);
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let options = SubmitOptions::new()
+        .with_interval(Duration::from_millis(50))
+        .with_stop_on_failure(true);
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert_eq!(
+        report.outcome,
+        SubmitOutcome::Failed {
+            bundle_id: mock.bundle_ids()[0].clone()
+        }
+    );
+    assert_eq!(report.attempts.len(), 1);
+    assert_eq!(send_bundle_count(&mock), 1);
+    assert!(report
+        .transitions
+        .iter()
+        .any(|t| t.to == BundleState::Failed));
+}
+
+#[tokio::test]
+async fn keeps_resubmitting_failed_bundles_by_default() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_bundle_outcome(MockBundleOutcome::Fail);
+    let sdk = JitoJsonRpcSDK::new(&mock.json_rpc_url(), None);
+    let options = SubmitOptions::new()
+        .with_interval(Duration::from_millis(50))
+        .with_timeout(Duration::from_millis(300));
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert_eq!(report.outcome, SubmitOutcome::TimedOut);
+    assert!(report.attempts.len() >= 2);
+    assert_eq!(report.attempts.len(), send_bundle_count(&mock));
+    assert!(report
+        .transitions
+        .iter()
+        .any(|t| t.to == BundleState::Failed));
+}
+
+#[tokio::test]
+async fn resubmits_with_each_region_uuid() {
+    let primary = MockBlockEngine::start().await.unwrap();
+    let secondary = MockBlockEngine::start().await.unwrap();
+    primary.set_landing_delay(Duration::from_millis(100));
+    let sdk = JitoJsonRpcSDK::new(&primary.json_rpc_url(), Some("primary-uuid".to_string()));
+    let options = SubmitOptions::new()
+        .with_interval(Duration::from_millis(50))
+        .with_region(JitoJsonRpcSDK::new(
+            &secondary.json_rpc_url(),
+            Some("secondary-uuid".to_string()),
+        ));
+
+    let report = sdk
+        .submit_until_landed(&[transfer_tx(&Keypair::new(), 1)], &options)
+        .await
+        .unwrap();
+    assert!(report.is_landed());
+    for (mock, uuid) in [
+        (&primary, "uuid=primary-uuid"),
+        (&secondary, "uuid=secondary-uuid"),
+    ] {
+        let requests = mock.requests();
+        let sends = requests
+            .iter()
+            .filter(|r| r.method == "sendBundle")
+            .collect::<Vec<_>>();
+        assert!(!sends.is_empty());
+        assert!(
+            sends.iter().all(|r| r.query.as_deref() == Some(uuid)),
+            "{:?}",
+            sends
+        );
+    }
+}
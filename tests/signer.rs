@@ -0,0 +1,176 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use jito_sdk_rust::{
+    sign_bundle, BundleBuilder, BundleSigner, JsonRpcTransport, RemoteSigner, TipContext,
+    TransportFuture, TransportResponse,
+};
+use serde_json::{json, Value};
+use solana_sdk::{
+    instruction::Instruction,
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::sync::Arc;
+
+// 未签名的交易，instructions 中的 signer 都需要签名
+fn unsigned(payer: &Pubkey, instructions: &[Instruction]) -> VersionedTransaction {
+    Transaction::new_unsigned(Message::new(instructions, Some(payer))).into()
+}
+
+fn verified(tx: &VersionedTransaction) -> bool {
+    tx.verify_with_results().iter().all(|ok| *ok)
+}
+
+// 内存中的签名服务，按 signMessage 协议用持有的 keypair 签名
+struct SigningService {
+    keypair: Keypair,
+    corrupt: bool,
+}
+
+impl JsonRpcTransport for SigningService {
+    fn post<'a>(&'a self, _url: &'a str, body: &'a Value) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let message = STANDARD
+                .decode(body["params"][0]["message"].as_str().unwrap())
+                .unwrap();
+            let signature = if self.corrupt {
+                Signature::default()
+            } else {
+                self.keypair.sign_message(&message)
+            };
+            Ok(TransportResponse::new(
+                200,
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "result": [{
+                        "pubkey": self.keypair.pubkey().to_string(),
+                        "signature": signature.to_string(),
+                    }],
+                }),
+            ))
+        })
+    }
+}
+
+#[tokio::test]
+async fn builder_signs_with_keypair() {
+    let payer = Keypair::new();
+    let transfer =
+        |lamports| system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports);
+    let transactions = BundleBuilder::new(payer.pubkey(), Pubkey::new_unique())
+        .add_transaction(unsigned(&payer.pubkey(), &[transfer(1)]))
+        .add_transaction(unsigned(&payer.pubkey(), &[transfer(2)]))
+        .signer(payer)
+        .build_signed(&TipContext::default())
+        .await
+        .unwrap();
+    assert!(transactions.iter().all(verified));
+}
+
+#[tokio::test]
+async fn signs_each_transaction_with_required_signers() {
+    let payer = Keypair::new();
+    let other = Keypair::new();
+    let unrelated = Keypair::new();
+    let mut transactions = vec![
+        unsigned(
+            &payer.pubkey(),
+            &[system_instruction::transfer(
+                &payer.pubkey(),
+                &Pubkey::new_unique(),
+                1,
+            )],
+        ),
+        unsigned(
+            &payer.pubkey(),
+            &[system_instruction::transfer(
+                &other.pubkey(),
+                &payer.pubkey(),
+                1,
+            )],
+        ),
+    ];
+    let signers: Vec<Box<dyn Signer + Send + Sync>> =
+        vec![Box::new(unrelated), Box::new(other), Box::new(payer)];
+
+    sign_bundle(&mut transactions, &signers).await.unwrap();
+    assert!(transactions.iter().all(verified));
+    assert_eq!(transactions[0].signatures.len(), 1);
+    assert_eq!(transactions[1].signatures.len(), 2);
+}
+
+#[tokio::test]
+async fn missing_signer_is_an_error() {
+    let payer = Keypair::new();
+    let other = Keypair::new();
+    let mut transactions = vec![unsigned(
+        &payer.pubkey(),
+        &[system_instruction::transfer(
+            &other.pubkey(),
+            &payer.pubkey(),
+            1,
+        )],
+    )];
+
+    let err = sign_bundle(&mut transactions, &payer).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Transaction 0 is missing signatures for: {}",
+            other.pubkey()
+        )
+    );
+}
+
+#[tokio::test]
+async fn combines_local_and_remote_signers() {
+    let payer = Keypair::new();
+    let remote_key = Keypair::new();
+    let remote_pubkey = remote_key.pubkey();
+    let remote = RemoteSigner::new("http://signer.local", vec![remote_pubkey]).with_transport(
+        Arc::new(SigningService {
+            keypair: remote_key,
+            corrupt: false,
+        }),
+    );
+    let signers: Vec<Box<dyn BundleSigner>> =
+        vec![Box::new(payer.insecure_clone()), Box::new(remote)];
+
+    let mut transactions = vec![unsigned(
+        &payer.pubkey(),
+        &[system_instruction::transfer(
+            &remote_pubkey,
+            &payer.pubkey(),
+            1,
+        )],
+    )];
+    sign_bundle(&mut transactions, &signers).await.unwrap();
+    assert!(verified(&transactions[0]));
+}
+
+#[tokio::test]
+async fn rejects_invalid_remote_signature() {
+    let remote_key = Keypair::new();
+    let remote_pubkey = remote_key.pubkey();
+    let remote = RemoteSigner::new("http://signer.local", vec![remote_pubkey]).with_transport(
+        Arc::new(SigningService {
+            keypair: remote_key,
+            corrupt: true,
+        }),
+    );
+
+    let mut transactions = vec![unsigned(
+        &remote_pubkey,
+        &[system_instruction::transfer(
+            &remote_pubkey,
+            &Pubkey::new_unique(),
+            1,
+        )],
+    )];
+    let err = sign_bundle(&mut transactions, &remote).await.unwrap_err();
+    assert!(err.to_string().contains("Invalid signature"));
+    assert!(!verified(&transactions[0]));
+}
@@ -0,0 +1,175 @@
+use jito_sdk_rust::metrics::REQUESTS_TOTAL;
+use jito_sdk_rust::{
+    BundleSimulator, InMemoryMetrics, MockBlockEngine, MockResponse, SimulateBundleConfig,
+};
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::sync::Arc;
+
+fn bundle(payer: &Keypair) -> Vec<VersionedTransaction> {
+    let transfer =
+        |lamports| system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), lamports);
+    let memo = Instruction::new_with_bytes(Pubkey::new_unique(), b"memo", vec![]);
+    [vec![transfer(1), memo], vec![transfer(2)]]
+        .iter()
+        .map(|ixs| {
+            Transaction::new_signed_with_payer(
+                ixs,
+                Some(&payer.pubkey()),
+                &[payer],
+                Hash::default(),
+            )
+            .into()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn simulate_bundle_returns_per_transaction_results() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let metrics = Arc::new(InMemoryMetrics::new());
+    let simulator = BundleSimulator::new(&mock.solana_rpc_url()).with_metrics(metrics.clone());
+    let payer = Keypair::new();
+    let transactions = bundle(&payer);
+    let config = SimulateBundleConfig {
+        pre_execution_accounts: vec![Some(vec![payer.pubkey(), Pubkey::new_unique()])],
+        post_execution_accounts: vec![None, Some(vec![payer.pubkey()])],
+        skip_sig_verify: true,
+        replace_recent_blockhash: true,
+    };
+
+    let result = simulator
+        .ensure_success(&transactions, &config)
+        .await
+        .unwrap();
+    assert!(result.is_success());
+    assert_eq!(result.failure_reason(), None);
+    assert!(result.slot >= 1_000);
+    assert_eq!(result.units_consumed(), 450);
+    let [first, second] = &result.transaction_results[..] else {
+        panic!("expected two results, got {:?}", result.transaction_results);
+    };
+    assert_eq!(first.err, None);
+    assert_eq!(first.units_consumed, Some(300));
+    assert_eq!(first.logs.as_ref().unwrap().len(), 4);
+    assert_eq!(first.pre_execution_accounts.as_ref().unwrap().len(), 2);
+    assert_eq!(first.post_execution_accounts, None);
+    assert_eq!(second.pre_execution_accounts, None);
+    assert_eq!(second.post_execution_accounts.as_ref().unwrap().len(), 1);
+
+    let request = mock.requests().pop().unwrap();
+    assert_eq!(request.method, "simulateBundle");
+    let options = &request.body["params"][1];
+    assert_eq!(options["skipSigVerify"], true);
+    assert_eq!(options["replaceRecentBlockhash"], true);
+    assert_eq!(options["transactionEncoding"], "base64");
+    assert_eq!(
+        metrics.counter(
+            REQUESTS_TOTAL,
+            &[
+                ("transport", "json_rpc"),
+                ("method", "simulateBundle"),
+                ("outcome", "ok"),
+            ],
+        ),
+        1
+    );
+}
+
+#[tokio::test]
+async fn failed_transaction_reports_logs_and_units() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    mock.set_simulation_failure(Some(1));
+    let simulator = BundleSimulator::new(&mock.solana_rpc_url());
+    let transactions = bundle(&Keypair::new());
+    let config = SimulateBundleConfig::default();
+
+    let result = simulator
+        .simulate_bundle(&transactions, &config)
+        .await
+        .unwrap();
+    assert!(!result.is_success());
+    let signature = transactions[1].signatures[0].to_string();
+    let reason = result.failure_reason().unwrap();
+    assert!(
+        reason.starts_with(&format!("Transaction {} failed:", signature)),
+        "{}",
+        reason
+    );
+    assert!(reason.contains("custom program error: 0x1"), "{}", reason);
+
+    let failed = &result.transaction_results[1];
+    assert!(failed.err.is_some());
+    assert_eq!(failed.units_consumed, Some(150));
+    assert!(failed.logs.as_ref().unwrap()[1].ends_with("failed: custom program error: 0x1"));
+    assert_eq!(result.units_consumed(), 450);
+
+    let err = simulator
+        .ensure_success(&transactions, &config)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Bundle simulation failed: Transaction"));
+}
+
+#[tokio::test]
+async fn account_configs_must_not_exceed_transactions() {
+    let mock = MockBlockEngine::start().await.unwrap();
+    let simulator = BundleSimulator::new(&mock.solana_rpc_url());
+    let transactions = bundle(&Keypair::new());
+
+    // 多于交易数时在本地报错，不发送请求
+    let config = SimulateBundleConfig {
+        pre_execution_accounts: vec![None, None, Some(vec![Pubkey::new_unique()])],
+        ..Default::default()
+    };
+    let err = simulator
+        .simulate_bundle(&transactions, &config)
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Got 3 account configs for 2 transactions");
+    assert!(mock.requests().is_empty());
+
+    // 少于交易数时用 null 补齐
+    let config = SimulateBundleConfig {
+        post_execution_accounts: vec![Some(vec![Pubkey::new_unique()])],
+        ..Default::default()
+    };
+    let result = simulator
+        .simulate_bundle(&transactions, &config)
+        .await
+        .unwrap();
+    assert!(result.is_success());
+    let options = &mock.requests()[0].body["params"][1];
+    assert_eq!(
+        options["preExecutionAccountsConfigs"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+    let post = options["postExecutionAccountsConfigs"].as_array().unwrap();
+    assert_eq!(post.len(), 2);
+    assert_eq!(post[0]["encoding"], "base64");
+    assert!(post[1].is_null());
+
+    mock.push_response(MockResponse::Reject {
+        code: -32602,
+        message: "invalid account config".to_string(),
+    });
+    let err = simulator
+        .simulate_bundle(&transactions, &config)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "simulateBundle failed: invalid account config"
+    );
+}
@@ -0,0 +1,81 @@
+use jito_sdk_rust::{MockTipFloorServer, TipFloorData, TipPercentile};
+use serde_json::json;
+use std::time::Duration;
+
+fn tip_floor(p50: f64) -> TipFloorData {
+    TipFloorData {
+        time: "2024-01-01T00:00:00Z".to_string(),
+        landed_tips_25th_percentile: p50 / 2.0,
+        landed_tips_50th_percentile: p50,
+        landed_tips_75th_percentile: p50 * 2.0,
+        landed_tips_95th_percentile: p50 * 4.0,
+        landed_tips_99th_percentile: p50 * 8.0,
+        ema_landed_tips_50th_percentile: p50 * 1.5,
+    }
+}
+
+#[test]
+fn tip_floor_parses_percentiles_and_ema() {
+    // Jito tip floor API 返回的字段
+    let data: TipFloorData = serde_json::from_value(json!({
+        "time": "2024-09-01T12:58:00Z",
+        "landed_tips_25th_percentile": 6.001000000000001e-6,
+        "landed_tips_50th_percentile": 1.0e-5,
+        "landed_tips_75th_percentile": 3.6196500000000005e-5,
+        "landed_tips_95th_percentile": 0.0014479055000000002,
+        "landed_tips_99th_percentile": 0.010007999,
+        "ema_landed_tips_50th_percentile": 1.2529734999999999e-5
+    }))
+    .unwrap();
+
+    assert_eq!(data.percentile(TipPercentile::P50), 1.0e-5);
+    assert_eq!(data.percentile(TipPercentile::Ema50), 1.2529734999999999e-5);
+    // 换算为 lamports 时向上取整
+    assert_eq!(data.percentile_lamports(TipPercentile::P25), 6_002);
+    assert_eq!(data.percentile_lamports(TipPercentile::P50), 10_000);
+    assert_eq!(data.percentile_lamports(TipPercentile::P75), 36_197);
+    assert_eq!(data.percentile_lamports(TipPercentile::P95), 1_447_906);
+    assert_eq!(data.percentile_lamports(TipPercentile::P99), 10_007_999);
+    assert_eq!(data.percentile_lamports(TipPercentile::Ema50), 12_530);
+}
+
+#[tokio::test]
+async fn fetch_returns_latest_tip_floor() {
+    let mock = MockTipFloorServer::start(tip_floor(0.00001)).await.unwrap();
+    let client = mock.client();
+    assert_eq!(client.fetch().await.unwrap(), tip_floor(0.00001));
+
+    mock.set(tip_floor(0.00002)).await;
+    let data = client.fetch().await.unwrap();
+    assert_eq!(data, tip_floor(0.00002));
+    assert_eq!(data.percentile_lamports(TipPercentile::P50), 20_000);
+}
+
+#[tokio::test]
+async fn subscribe_receives_pushed_updates() {
+    let mock = MockTipFloorServer::start(tip_floor(0.00001)).await.unwrap();
+    let mut updates = mock.client().subscribe().await.unwrap();
+
+    mock.push(tip_floor(0.00003)).await;
+    mock.push(tip_floor(0.00004)).await;
+    for expected in [tip_floor(0.00003), tip_floor(0.00004)] {
+        let update = tokio::time::timeout(Duration::from_secs(5), updates.recv())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(update, expected);
+    }
+    // push 同时更新 REST 返回的数据
+    assert_eq!(mock.client().fetch().await.unwrap(), tip_floor(0.00004));
+}
+
+#[tokio::test]
+async fn fetch_fails_when_server_is_unreachable() {
+    let mock = MockTipFloorServer::start(tip_floor(0.00001)).await.unwrap();
+    let client = mock.client();
+    drop(mock);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(client.fetch().await.is_err());
+    assert!(client.subscribe().await.is_err());
+}
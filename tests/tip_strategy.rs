@@ -0,0 +1,139 @@
+use jito_sdk_rust::{
+    EscalatingTip, FixedTip, PercentileTip, ProfitShareTip, TipContext, TipFloorData,
+    TipPercentile, TipStrategy,
+};
+
+fn tip_floor() -> TipFloorData {
+    TipFloorData {
+        time: "2024-01-01T00:00:00Z".to_string(),
+        landed_tips_25th_percentile: 0.000005,
+        landed_tips_50th_percentile: 0.00001,
+        landed_tips_75th_percentile: 0.00004,
+        landed_tips_95th_percentile: 0.001,
+        landed_tips_99th_percentile: 0.01,
+        ema_landed_tips_50th_percentile: 0.000012,
+    }
+}
+
+fn with_tip_floor() -> TipContext {
+    TipContext {
+        tip_floor: Some(tip_floor()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fixed_tip_ignores_context() {
+    assert_eq!(
+        FixedTip(1_000)
+            .tip_lamports(&TipContext::default())
+            .unwrap(),
+        1_000
+    );
+    assert_eq!(
+        FixedTip(1_000).tip_lamports(&with_tip_floor()).unwrap(),
+        1_000
+    );
+}
+
+#[test]
+fn percentile_tip_clamps_to_bounds() {
+    let ctx = with_tip_floor();
+    let tip = |percentile, min, max| {
+        PercentileTip::new(percentile, min, max)
+            .tip_lamports(&ctx)
+            .unwrap()
+    };
+    assert_eq!(tip(TipPercentile::P50, 1_000, 100_000), 10_000);
+    assert_eq!(tip(TipPercentile::Ema50, 1_000, 100_000), 12_000);
+    assert_eq!(tip(TipPercentile::P25, 8_000, 100_000), 8_000);
+    assert_eq!(tip(TipPercentile::P99, 1_000, 100_000), 100_000);
+    // min 大于 max 时不会 panic，以 max 为准
+    assert_eq!(tip(TipPercentile::P50, 50_000, 20_000), 20_000);
+
+    let err = PercentileTip::new(TipPercentile::P50, 0, u64::MAX)
+        .tip_lamports(&TipContext::default())
+        .unwrap_err();
+    assert!(err.to_string().contains("Tip floor data is required"));
+}
+
+#[test]
+fn profit_share_tip_is_capped() {
+    let ctx = |profit| TipContext {
+        expected_profit_lamports: Some(profit),
+        ..Default::default()
+    };
+    let strategy = ProfitShareTip::new(0.5, 30_000);
+    assert_eq!(strategy.tip_lamports(&ctx(40_000)).unwrap(), 20_000);
+    assert_eq!(strategy.tip_lamports(&ctx(100_000)).unwrap(), 30_000);
+    assert_eq!(strategy.tip_lamports(&ctx(0)).unwrap(), 0);
+
+    // share 限制在 0.0 ~ 1.0
+    assert_eq!(ProfitShareTip::new(2.0, u64::MAX).share(), 1.0);
+    assert_eq!(ProfitShareTip::new(-1.0, u64::MAX).share(), 0.0);
+    assert_eq!(
+        ProfitShareTip::new(2.0, u64::MAX)
+            .tip_lamports(&ctx(40_000))
+            .unwrap(),
+        40_000
+    );
+
+    let err = strategy.tip_lamports(&TipContext::default()).unwrap_err();
+    assert!(err.to_string().contains("Expected profit is required"));
+}
+
+#[test]
+fn escalating_tip_raises_bid_per_retry() {
+    let strategy = EscalatingTip::new(FixedTip(1_000), 2.0, 5_000);
+    let tips = (0..5)
+        .map(|attempt| {
+            strategy
+                .tip_lamports(&TipContext {
+                    attempt,
+                    ..Default::default()
+                })
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(tips, vec![1_000, 2_000, 4_000, 5_000, 5_000]);
+
+    // 重试次数很大时出价停在上限，不会因指数溢出而变小
+    for attempt in [i32::MAX as u32, i32::MAX as u32 + 1, u32::MAX] {
+        let ctx = TipContext {
+            attempt,
+            ..Default::default()
+        };
+        assert_eq!(strategy.tip_lamports(&ctx).unwrap(), 5_000);
+    }
+
+    // 内部策略的错误原样返回
+    let strategy = EscalatingTip::new(
+        PercentileTip::new(TipPercentile::P50, 0, u64::MAX),
+        1.5,
+        u64::MAX,
+    );
+    assert!(strategy.tip_lamports(&TipContext::default()).is_err());
+    let ctx = TipContext {
+        attempt: 2,
+        ..with_tip_floor()
+    };
+    assert_eq!(strategy.tip_lamports(&ctx).unwrap(), 22_500);
+}
+
+#[test]
+fn boxed_strategies_are_interchangeable() {
+    let strategies: Vec<Box<dyn TipStrategy>> = vec![
+        Box::new(FixedTip(1_000)),
+        Box::new(PercentileTip::new(TipPercentile::P75, 0, u64::MAX)),
+        Box::new(EscalatingTip::new(FixedTip(1_000), 3.0, u64::MAX)),
+    ];
+    let ctx = TipContext {
+        attempt: 1,
+        ..with_tip_floor()
+    };
+    let tips = strategies
+        .iter()
+        .map(|strategy| strategy.tip_lamports(&ctx).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(tips, vec![1_000, 40_000, 3_000]);
+}